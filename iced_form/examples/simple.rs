@@ -5,11 +5,12 @@ use derive_builder::Builder;
 use iced::{
     advanced::Application,
     alignment, executor,
-    widget::{button, column, toggler},
+    widget::{button, column, text, toggler},
     Command, Element, Font, Renderer, Settings, Theme,
 };
 use iced_form::{
     form_field::{self, FormField},
+    layout::{Grid, LabelPosition},
     Catalog,
 };
 
@@ -131,21 +132,38 @@ impl VehicleForm {
     where
         Theme: Catalog + 'a,
     {
-        column![
-            self.name.view().map(Message::Name),
-            self.num_wheels.view().map(Message::NumWheels),
-            self.weight.view().map(Message::Weight),
-            toggler(
-                Some("Licensed".to_string()),
-                self.builder.licensed.unwrap_or(false),
-                Message::Licensed
+        let fields = Grid::new(LabelPosition::Right)
+            .push(
+                text(self.name.label()),
+                self.name.view_input().map(Message::Name),
+            )
+            .push(
+                text(self.num_wheels.label()),
+                self.num_wheels.view_input().map(Message::NumWheels),
+            )
+            .push(
+                text(self.weight.label()),
+                self.weight.view_input().map(Message::Weight),
             )
-            .text_alignment(alignment::Horizontal::Left),
-            iced::widget::pick_list(
-                VehicleType::ALL,
-                self.selected_vehicle_type.clone(),
-                Message::VehicleType
-            ),
+            .push(
+                text("Licensed"),
+                toggler(
+                    None,
+                    self.builder.licensed.unwrap_or(false),
+                    Message::Licensed,
+                )
+                .text_alignment(alignment::Horizontal::Left),
+            )
+            .push(
+                text("Vehicle Type"),
+                iced::widget::pick_list(
+                    VehicleType::ALL,
+                    self.selected_vehicle_type.clone(),
+                    Message::VehicleType,
+                ),
+            );
+        column![
+            fields,
             button("Submit").on_press_maybe(self.builder.build().ok().map(Message::Vehicle))
         ]
         .into()
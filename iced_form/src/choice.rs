@@ -0,0 +1,359 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{button, checkbox, column, combo_box, pick_list, radio, row, text},
+    Alignment, Element,
+};
+
+use crate::Catalog;
+
+/// Whether the choices of a [`Picker`] are laid out next to or below each other.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// The widget picking one of the variants of a unit enum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Picker {
+    /// A drop down, fitting any number of variants.
+    #[default]
+    PickList,
+    /// A radio button per variant.
+    Radio(Orientation),
+    /// A button per variant, styled with [`Catalog::segment`] after whether it is selected.
+    Segmented(Orientation),
+    /// A text input filtering the variants as one types, for enums with many variants.
+    ComboBox,
+}
+
+/// The variants of a unit enum offered by its picker, `ALL` for the enums deriving `FormBuilder`.
+pub trait Variants: Sized {
+    fn variants() -> Vec<Self>;
+}
+
+/// An option of a [`Choice`] for an `Option<T>` field, `None` is shown with its label.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrNone<T> {
+    None(String),
+    Some(T),
+}
+
+impl<T> OrNone<T> {
+    /// `None` labeled `none` followed by every one of `options`.
+    pub fn options(none: &str, options: impl IntoIterator<Item = T>) -> Vec<Self> {
+        std::iter::once(Self::None(none.to_string()))
+            .chain(options.into_iter().map(Self::Some))
+            .collect()
+    }
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Self::None(_) => None,
+            Self::Some(value) => Some(value),
+        }
+    }
+}
+
+impl<T: Display> Display for OrNone<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None(label) => write!(f, "{label}"),
+            Self::Some(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The variants of a unit enum along with the [`Picker`] choosing among them.
+pub struct Choice<T> {
+    picker: Picker,
+    options: Vec<T>,
+    /// The filter typed into the combo box, kept while it is the picker
+    combo_box: Option<combo_box::State<T>>,
+    /// Options marked with a warning badge
+    deprecated: Vec<T>,
+}
+
+impl<T> Choice<T>
+where
+    T: Display + PartialEq + Clone + 'static,
+{
+    pub fn new(picker: Picker, options: impl Into<Vec<T>>) -> Self {
+        let mut choice = Self {
+            picker: Picker::PickList,
+            options: options.into(),
+            combo_box: None,
+            deprecated: Vec::new(),
+        };
+        choice.set_picker(picker);
+        choice
+    }
+    pub fn set_picker(&mut self, picker: Picker) {
+        self.picker = picker;
+        self.combo_box = match picker {
+            Picker::ComboBox => Some(combo_box::State::new(self.options.clone())),
+            _ => None,
+        };
+    }
+    /// Marks `deprecated` with a warning badge, they can still be picked.
+    pub fn deprecated(mut self, deprecated: impl Into<Vec<T>>) -> Self {
+        self.deprecated = deprecated.into();
+        self
+    }
+    pub fn picker(&self) -> Picker {
+        self.picker
+    }
+    pub fn options(&self) -> &[T] {
+        &self.options
+    }
+    /// Picks one of the options, publishing `on_select` with the chosen one. Unless `editable`,
+    /// only the `selected` value is shown.
+    ///
+    /// A `selected` value that is not among the options, e.g. a variant left out of the picker,
+    /// is still shown but cannot be picked again once another one is.
+    pub fn view<'a, Message, Theme>(
+        &'a self,
+        editable: bool,
+        selected: Option<&T>,
+        on_select: impl Fn(T) -> Message + 'static,
+    ) -> Element<'a, Message, Theme>
+    where
+        Message: Clone + 'a,
+        Theme: Catalog + 'a,
+    {
+        // Segmented buttons are shown disabled, the other pickers only show the value picked
+        if !editable && !matches!(self.picker, Picker::Segmented(_)) {
+            let selected = selected.map_or_else(|| "-".to_string(), T::to_string);
+            return text(selected).into();
+        }
+        let listed = matches!(self.picker, Picker::PickList | Picker::ComboBox);
+        let note = match selected {
+            Some(selected) if !listed && !self.options.contains(selected) => {
+                Some(selected.to_string())
+            }
+            Some(selected) if listed && self.deprecated.contains(selected) => {
+                Some("⚠ Deprecated".to_string())
+            }
+            _ => None,
+        };
+        let picker = self.view_picker(editable, selected, on_select);
+        match note {
+            Some(note) => row![picker, text(note).size(12)]
+                .spacing(10)
+                .align_items(Alignment::Center)
+                .into(),
+            None => picker,
+        }
+    }
+    fn view_picker<'a, Message, Theme>(
+        &'a self,
+        editable: bool,
+        selected: Option<&T>,
+        on_select: impl Fn(T) -> Message + 'static,
+    ) -> Element<'a, Message, Theme>
+    where
+        Message: Clone + 'a,
+        Theme: Catalog + 'a,
+    {
+        let orientation = match (self.picker, &self.combo_box) {
+            (Picker::ComboBox, Some(state)) => {
+                return combo_box(state, "Type to search", selected, on_select).into()
+            }
+            (Picker::PickList | Picker::ComboBox, _) => {
+                return pick_list(self.options.as_slice(), selected.cloned(), on_select).into()
+            }
+            (Picker::Radio(orientation) | Picker::Segmented(orientation), _) => orientation,
+        };
+        let selected_idx =
+            selected.and_then(|selected| self.options.iter().position(|option| option == selected));
+        let choices = self.options.iter().enumerate().map(|(idx, option)| {
+            let message = on_select(option.clone());
+            let label = match self.deprecated.contains(option) {
+                true => format!("{option} ⚠"),
+                false => option.to_string(),
+            };
+            match self.picker {
+                Picker::Radio(_) => radio(label, idx, selected_idx, |_| message).into(),
+                _ => button(text(label))
+                    .padding([4, 10])
+                    .class(Theme::segment(Some(idx) == selected_idx))
+                    .on_press_maybe(editable.then_some(message))
+                    .into(),
+            }
+        });
+        match orientation {
+            Orientation::Horizontal => row(choices).spacing(10).into(),
+            Orientation::Vertical => column(choices).spacing(5).into(),
+        }
+    }
+}
+
+/// Any number of the variants of a unit enum, picked with a checkbox each.
+pub struct Selection<T> {
+    options: Vec<T>,
+    /// Whether the option at the same index is picked
+    checked: Vec<bool>,
+    /// Values picked that are not among the options, kept picked and shown read-only
+    unlisted: Vec<T>,
+    min: Option<usize>,
+    max: Option<usize>,
+    /// "All" and "None" buttons checking or unchecking every option at once
+    shortcuts: bool,
+}
+
+impl<T> Selection<T>
+where
+    T: Display + PartialEq + Clone + 'static,
+{
+    pub fn new(options: impl Into<Vec<T>>) -> Self {
+        let options = options.into();
+        Self {
+            checked: vec![false; options.len()],
+            options,
+            unlisted: Vec::new(),
+            min: None,
+            max: None,
+            shortcuts: false,
+        }
+    }
+    /// Between `min` and `max` options have to be picked for the selection to build.
+    pub fn set_bounds(&mut self, min: Option<usize>, max: Option<usize>) {
+        self.min = min;
+        self.max = max;
+    }
+    pub fn set_shortcuts(&mut self, shortcuts: bool) {
+        self.shortcuts = shortcuts;
+    }
+    pub fn toggle(&mut self, option: &T, checked: bool) {
+        if let Some(idx) = self.options.iter().position(|o| o == option) {
+            self.checked[idx] = checked;
+        }
+    }
+    /// Picks `value`, a value that is not among the options, e.g. a variant left out of them, is
+    /// kept picked and cannot be unchecked.
+    pub fn pick(&mut self, value: T) {
+        match self.options.iter().position(|option| *option == value) {
+            Some(idx) => self.checked[idx] = true,
+            None if !self.unlisted.contains(&value) => self.unlisted.push(value),
+            None => {}
+        }
+    }
+    pub fn set_all(&mut self, checked: bool) {
+        self.checked.fill(checked);
+    }
+    /// The picked options, in the order they are listed, followed by the values picked that are
+    /// not listed.
+    pub fn selected(&self) -> impl Iterator<Item = &T> {
+        self.options
+            .iter()
+            .zip(&self.checked)
+            .filter_map(|(option, checked)| checked.then_some(option))
+            .chain(&self.unlisted)
+    }
+    /// Why the number of picked options is out of bounds, if it is.
+    pub fn error(&self) -> Option<String> {
+        let count = self.selected().count();
+        match (self.min, self.max) {
+            (Some(min), _) if count < min => Some(format!("Select at least {min}")),
+            (_, Some(max)) if count > max => Some(format!("Select at most {max}")),
+            _ => None,
+        }
+    }
+    /// The picked options collected into `C`, unless their number is out of bounds.
+    pub fn build<C: FromIterator<T>>(&self) -> Option<C> {
+        match self.error() {
+            Some(_) => None,
+            None => Some(self.selected().cloned().collect()),
+        }
+    }
+    /// A checkbox per option publishing `on_toggle`, along with the shortcuts publishing
+    /// `on_all`, all of them disabled unless `editable`. Values picked that are not listed get a
+    /// disabled checkbox each.
+    pub fn view<'a, Message, Theme>(
+        &'a self,
+        editable: bool,
+        on_toggle: impl Fn(T, bool) -> Message + Clone + 'a,
+        on_all: impl Fn(bool) -> Message,
+    ) -> Element<'a, Message, Theme>
+    where
+        Message: Clone + 'a,
+        Theme: Catalog + 'a,
+    {
+        let checkboxes = self
+            .options
+            .iter()
+            .zip(&self.checked)
+            .map(|(option, checked)| {
+                let on_toggle = on_toggle.clone();
+                checkbox(option.to_string(), *checked)
+                    .on_toggle_maybe(
+                        editable.then_some(move |checked| on_toggle(option.clone(), checked)),
+                    )
+                    .into()
+            })
+            .chain(
+                self.unlisted
+                    .iter()
+                    .map(|value| checkbox(value.to_string(), true).into()),
+            );
+        let mut content = column(checkboxes).spacing(5);
+        if self.shortcuts {
+            content = content.push(
+                row![
+                    button("All").on_press_maybe(editable.then(|| on_all(true))),
+                    button("None").on_press_maybe(editable.then(|| on_all(false))),
+                ]
+                .spacing(10),
+            );
+        }
+        if let Some(error) = self.error() {
+            content = content.push(text(error).size(12));
+        }
+        content.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::Selection;
+
+    #[test]
+    fn build_in_listed_order() {
+        let mut selection = Selection::new([3, 1, 2]);
+        selection.toggle(&2, true);
+        selection.toggle(&3, true);
+        assert_eq!(selection.build::<Vec<_>>(), Some(vec![3, 2]));
+        selection.toggle(&3, false);
+        assert_eq!(selection.build::<BTreeSet<_>>(), Some(BTreeSet::from([2])));
+    }
+
+    #[test]
+    fn build_within_bounds() {
+        let mut selection = Selection::new([1, 2, 3]);
+        selection.set_bounds(Some(1), Some(2));
+        assert_eq!(selection.build::<Vec<_>>(), None);
+        assert_eq!(selection.error(), Some("Select at least 1".to_string()));
+        selection.set_all(true);
+        assert_eq!(selection.build::<Vec<_>>(), None);
+        assert_eq!(selection.error(), Some("Select at most 2".to_string()));
+        selection.toggle(&1, false);
+        assert_eq!(selection.build::<Vec<_>>(), Some(vec![2, 3]));
+    }
+
+    #[test]
+    fn unlisted_values_kept() {
+        let mut selection = Selection::new([1, 2]);
+        selection.set_bounds(None, Some(2));
+        selection.pick(4);
+        selection.pick(2);
+        selection.pick(4);
+        assert_eq!(selection.build::<Vec<_>>(), Some(vec![2, 4]));
+        selection.set_all(false);
+        assert_eq!(selection.build::<Vec<_>>(), Some(vec![4]));
+        selection.set_all(true);
+        assert_eq!(selection.error(), Some("Select at most 2".to_string()));
+    }
+}
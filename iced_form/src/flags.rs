@@ -0,0 +1,156 @@
+use bitflags::{
+    parser::{ParseHex, WriteHex},
+    Flags,
+};
+use iced::{
+    widget::{checkbox, column, text, text_input},
+    Command, Element,
+};
+
+use crate::{search, Catalog, Form};
+
+#[derive(Clone, Debug)]
+pub enum Message {
+    /// The named flag at `flag` in `Flags::FLAGS` was checked or unchecked
+    Toggled { flag: usize, checked: bool },
+    /// Input of the raw hex view
+    Hex(String),
+}
+
+/// The form of a [`bitflags::Flags`] type, a checkbox per named flag.
+pub struct FlagsForm<F> {
+    value: F,
+    /// The raw bits in hex, shown when `Some`
+    hex: Option<String>,
+    invalid_hex: bool,
+    readonly: bool,
+}
+
+impl<F: Flags> Default for FlagsForm<F> {
+    fn default() -> Self {
+        Self {
+            value: F::empty(),
+            hex: None,
+            invalid_hex: false,
+            readonly: false,
+        }
+    }
+}
+
+impl<F> FlagsForm<F>
+where
+    F: Flags,
+    F::Bits: ParseHex + WriteHex,
+{
+    /// Shows the raw bits in a hex input along with the checkboxes
+    #[doc(hidden)]
+    pub fn set_hex(&mut self, hex: bool) {
+        self.hex = hex.then(|| to_hex(&self.value));
+    }
+}
+
+impl<F> Form for FlagsForm<F>
+where
+    F: Flags,
+    F::Bits: ParseHex + WriteHex,
+{
+    type Value = F;
+    type Message = Message;
+
+    fn from_value(value: F) -> Self {
+        Self {
+            value,
+            ..Self::default()
+        }
+    }
+
+    fn view_nested<'a, Theme>(&'a self) -> Element<'a, Message, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        let editable = !self.readonly;
+        let checkboxes = F::FLAGS
+            .iter()
+            .enumerate()
+            .filter(|(_, flag)| flag.is_named())
+            .map(|(idx, flag)| {
+                let checked = self
+                    .value
+                    .contains(F::from_bits_retain(flag.value().bits()));
+                checkbox(flag.name(), checked)
+                    .on_toggle_maybe(
+                        editable.then_some(move |checked| Message::Toggled { flag: idx, checked }),
+                    )
+                    .into()
+            });
+        let mut content = column(checkboxes).spacing(5);
+        if let Some(hex) = &self.hex {
+            content = content
+                .push(text_input("0x0", hex).on_input_maybe(editable.then_some(Message::Hex)));
+        }
+        if self.invalid_hex {
+            content = content.push(text("Not a hexadecimal number").size(12));
+        }
+        content.into()
+    }
+    fn view_filtered<'a, Theme>(&'a self, query: &str) -> Option<Element<'a, Message, Theme>>
+    where
+        Theme: Catalog + 'a,
+    {
+        F::FLAGS
+            .iter()
+            .any(|flag| search::matches(query, &[flag.name()]))
+            .then(|| self.view_nested())
+    }
+    /// Keeps the flags from changing, the path is ignored as the flags have no fields
+    fn set_readonly(&mut self, _path: &str, readonly: bool) {
+        self.readonly = readonly;
+    }
+    /// Empty while the hex input does not parse
+    fn first_invalid(&self) -> Option<String> {
+        self.invalid_hex.then(String::new)
+    }
+    /// The checked flags, along with any other bits set through the hex input
+    fn build(&self) -> Option<F> {
+        (!self.invalid_hex).then(|| F::from_bits_retain(self.value.bits()))
+    }
+    fn update(&mut self, message: Message) -> Command<Message> {
+        if self.readonly {
+            return Command::none();
+        }
+        match message {
+            Message::Toggled { flag, checked } => {
+                if let Some(flag) = F::FLAGS.get(flag) {
+                    self.value
+                        .set(F::from_bits_retain(flag.value().bits()), checked);
+                    self.invalid_hex = false;
+                    if self.hex.is_some() {
+                        self.hex = Some(to_hex(&self.value));
+                    }
+                }
+            }
+            Message::Hex(input) => {
+                let digits = input.trim().trim_start_matches("0x");
+                match F::Bits::parse_hex(digits) {
+                    Ok(bits) => {
+                        self.value = F::from_bits_retain(bits);
+                        self.invalid_hex = false;
+                    }
+                    Err(_) => self.invalid_hex = true,
+                }
+                self.hex = Some(input);
+            }
+        }
+        Command::none()
+    }
+}
+
+fn to_hex<F>(value: &F) -> String
+where
+    F: Flags,
+    F::Bits: WriteHex,
+{
+    let mut hex = String::from("0x");
+    let _ = value.bits().write_hex(&mut hex);
+    hex
+}
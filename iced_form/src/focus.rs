@@ -0,0 +1,251 @@
+use iced::{
+    advanced::{
+        layout, mouse, renderer,
+        widget::{operation::Focusable, Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event,
+    widget::scrollable,
+    Command, Element, Event, Length, Rectangle, Size,
+};
+
+/// Focuses the first input of the field at `path` within the [`scrollable`] with the given id,
+/// unfocusing every other input.
+///
+/// `path` is the dotted path of the field as for [`crate::scroll::scroll_to`].
+pub fn focus<T>(scrollable: scrollable::Id, path: &str) -> Command<T>
+where
+    T: Send + 'static,
+{
+    Command::widget(FocusField {
+        scrollable: scrollable.into(),
+        path: path
+            .split('.')
+            .map(|segment| Id::new(segment.to_string()))
+            .collect(),
+        inside: false,
+        depth: 0,
+        in_field: false,
+        focused: false,
+    })
+}
+
+struct FocusField {
+    scrollable: Id,
+    path: Vec<Id>,
+    inside: bool,
+    /// Number of path segments matched by the containers we are currently in
+    depth: usize,
+    in_field: bool,
+    focused: bool,
+}
+
+impl<T> Operation<T> for FocusField
+where
+    T: 'static,
+{
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if id == Some(&self.scrollable) {
+            self.inside = true;
+            operate_on_children(self);
+            self.inside = false;
+            return;
+        }
+        if self.inside && !self.in_field && id.is_some() && id == self.path.get(self.depth) {
+            if self.depth + 1 == self.path.len() {
+                self.in_field = true;
+                operate_on_children(self);
+                self.in_field = false;
+                return;
+            }
+            self.depth += 1;
+            operate_on_children(self);
+            self.depth -= 1;
+            return;
+        }
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        if self.in_field && !self.focused {
+            state.focus();
+            self.focused = true;
+        } else {
+            state.unfocus();
+        }
+    }
+}
+
+/// Publishes a message when its content, usually a text input, loses focus.
+pub(crate) struct OnBlur<'a, Message, Theme, Renderer> {
+    content: Element<'a, Message, Theme, Renderer>,
+    on_blur: Message,
+}
+
+impl<'a, Message, Theme, Renderer> OnBlur<'a, Message, Theme, Renderer> {
+    pub(crate) fn new(
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+        on_blur: Message,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            on_blur,
+        }
+    }
+
+    fn is_focused(&self, tree: &mut Tree, layout: Layout<'_>, renderer: &Renderer) -> bool
+    where
+        Renderer: renderer::Renderer,
+    {
+        let mut probe = FocusProbe { focused: false };
+        self.content
+            .as_widget()
+            .operate(tree, layout, renderer, &mut probe);
+        probe.focused
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for OnBlur<'a, Message, Theme, Renderer>
+where
+    Message: Clone,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        self.content
+            .as_widget()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        let was_focused = self.is_focused(&mut tree.children[0], layout, renderer);
+        let status = self.content.as_widget_mut().on_event(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+        if was_focused && !self.is_focused(&mut tree.children[0], layout, renderer) {
+            shell.publish(self.on_blur.clone());
+        }
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.as_widget().mouse_interaction(
+            &tree.children[0],
+            layout,
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget().draw(
+            &tree.children[0],
+            renderer,
+            theme,
+            style,
+            layout,
+            cursor,
+            viewport,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<OnBlur<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(on_blur: OnBlur<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(on_blur)
+    }
+}
+
+/// Finds out whether any focusable widget it visits is focused.
+struct FocusProbe {
+    focused: bool,
+}
+
+impl<T> Operation<T> for FocusProbe {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self);
+    }
+
+    fn focusable(&mut self, state: &mut dyn Focusable, _id: Option<&Id>) {
+        self.focused |= state.is_focused();
+    }
+}
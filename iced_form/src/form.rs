@@ -0,0 +1,121 @@
+use std::fmt::{Debug, Display};
+
+use iced::{Command, Element};
+
+use crate::{
+    choice::{Picker, Variants},
+    nav::Node,
+    validate::{FormErrors, PathErrors},
+    Catalog,
+};
+
+/// A form editing a value of type [`Form::Value`], either on its own or as the field of an
+/// enclosing form.
+///
+/// Implemented by the forms generated with `#[derive(FormBuilder)]`, the enclosing forms reach
+/// their nested forms through it. Forms without fields of their own, e.g. of unit enums, only
+/// implement the required methods.
+pub trait Form {
+    type Value;
+    type Message: Clone + Debug + Send + 'static;
+
+    /// A form starting from `value`, e.g. to edit a value loaded from a file. The `Default` of a
+    /// form starts from the `Default` of its value.
+    fn from_value(value: Self::Value) -> Self
+    where
+        Self: Sized;
+    /// The value edited, unless a field does not build or fails a validator.
+    fn build(&self) -> Option<Self::Value>;
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message>;
+    /// The fields without a title or Submit button, to be shown within an enclosing form.
+    fn view_nested<'a, Theme>(&'a self) -> Element<'a, Self::Message, Theme>
+    where
+        Theme: Catalog + 'a;
+    /// The fields matching `query`, see [`crate::search::matches`], `None` when none does.
+    fn view_filtered<'a, Theme>(&'a self, query: &str) -> Option<Element<'a, Self::Message, Theme>>
+    where
+        Theme: Catalog + 'a;
+    /// Makes the field at the dotted `path` read-only or editable again, an empty path stands for
+    /// the whole form. Read-only fields are still built with the value shown.
+    fn set_readonly(&mut self, path: &str, readonly: bool);
+
+    /// Starts the asynchronous validators on the values the form starts with, the fields have no
+    /// value until checked. To be returned from `Application::new` along with the form.
+    fn init(&mut self) -> Command<Self::Message> {
+        Command::none()
+    }
+    /// The fields laid out in `columns` columns, as the top-level view of an enclosing form does
+    /// for its own. Forms without fields of their own show their nested view.
+    #[doc(hidden)]
+    fn view_columns<'a, Theme>(&'a self, _columns: usize) -> Element<'a, Self::Message, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        self.view_nested()
+    }
+    /// The nested form at the dotted `path`, the form itself for an empty path.
+    fn view_at<'a, Theme>(&'a self, path: &str) -> Option<Element<'a, Self::Message, Theme>>
+    where
+        Theme: Catalog + 'a,
+    {
+        path.is_empty().then(|| self.view_nested())
+    }
+    /// The node of the form in the navigation tree, `None` for forms without nested forms.
+    fn nav_tree(&self, _label: &str, _path: &str) -> Option<Node> {
+        None
+    }
+    /// Shows the errors of the validators of the enclosing forms below the fields.
+    #[doc(hidden)]
+    fn set_errors(&mut self, _errors: PathErrors) {}
+    /// Shows `errors` computed outside the form below the fields at their dotted paths, replacing
+    /// those applied before. Each error is kept until its field is edited.
+    ///
+    /// Forms without fields of their own leave their errors to the enclosing form.
+    fn apply_errors(&mut self, _errors: FormErrors) {}
+    /// Whether `message` edits the value, rather than e.g. reporting that a field lost focus.
+    /// Errors applied with [`Form::apply_errors`] are cleared on edits only.
+    #[doc(hidden)]
+    fn is_edit(&self, _message: &Self::Message) -> bool {
+        true
+    }
+    /// Whether an asynchronous validator is still checking a value of the form.
+    #[doc(hidden)]
+    fn is_pending(&self) -> bool {
+        false
+    }
+    /// Shows every error regardless of `show_errors`, as Submit was pressed.
+    #[doc(hidden)]
+    fn submit_attempted(&mut self) {}
+    /// The dotted path of the first field that does not build or has an error, empty for the
+    /// form itself.
+    #[doc(hidden)]
+    fn first_invalid(&self) -> Option<String> {
+        None
+    }
+}
+
+/// The form of an enum, picking one of its variants before editing the fields of that variant.
+///
+/// Implemented by the forms generated for enums, [`crate::option::OptionForm`] offers `None`
+/// along with the variants in the same picker through it.
+pub trait EnumForm: Form {
+    /// The variants to pick from, the enum itself for enums without fields.
+    type Kind: Variants + Display + Debug + Clone + PartialEq + Send + 'static;
+
+    /// The variant picked, if any.
+    fn kind(&self) -> Option<Self::Kind>;
+    /// Switches to the `kind` variant, as if picked in the form.
+    fn pick(&mut self, kind: Self::Kind) -> Command<Self::Message>;
+    fn picker(&self) -> Picker;
+    /// The fields of the variant picked, without the picker, `None` for variants without fields.
+    fn view_fields<'a, Theme>(&'a self) -> Option<Element<'a, Self::Message, Theme>>
+    where
+        Theme: Catalog + 'a;
+    /// The fields of the variant picked matching `query`, `None` when none does.
+    fn view_fields_filtered<'a, Theme>(
+        &'a self,
+        query: &str,
+    ) -> Option<Element<'a, Self::Message, Theme>>
+    where
+        Theme: Catalog + 'a;
+}
@@ -1,14 +1,20 @@
 use std::{
     fmt::{Debug, Display},
+    future::Future,
     str::FromStr,
 };
 
 use iced::{
-    widget::{row, text, text_input},
+    widget::{column, text, text_input},
     Command, Element,
 };
 
-use crate::Catalog;
+use crate::{
+    focus::OnBlur,
+    layout::{Grid, LabelPosition},
+    validate::{with_error, ShowErrors, Validator},
+    Catalog,
+};
 
 #[derive(Clone, Debug)]
 pub enum Message<T>
@@ -20,8 +26,25 @@ where
         invalid_reason: String,
     },
     Value((T, String)),
+    /// Result of the asynchronous validator for the input numbered `generation`
+    Checked {
+        generation: u64,
+        result: Result<(), String>,
+    },
+    /// The input lost focus
+    Blur,
+}
+
+impl<T: Clone> Message<T> {
+    /// Whether the message changes the input, rather than reporting on it.
+    pub fn is_edit(&self) -> bool {
+        matches!(self, Self::Input { .. } | Self::Value(_))
+    }
 }
 
+/// Starts the asynchronous validator for a value, numbered by its generation
+type StartCheck<T> = Box<dyn Fn(T, u64) -> Command<Message<T>>>;
+
 pub struct FormField<T>
 where
     T: Clone,
@@ -30,6 +53,15 @@ where
     value: Option<T>,
     value_str: String,
     invalid_reason: Option<String>,
+    validators: Vec<Validator<T>>,
+    validate_async: Option<StartCheck<T>>,
+    /// Number of the latest input, results of older inputs are discarded
+    generation: u64,
+    pending: bool,
+    show_errors: ShowErrors,
+    touched: bool,
+    blurred: bool,
+    submitted: bool,
 }
 impl<T> FormField<T>
 where
@@ -41,28 +73,185 @@ where
             value: None,
             value_str: Default::default(),
             invalid_reason: None,
+            validators: Vec::new(),
+            validate_async: None,
+            generation: 0,
+            pending: false,
+            show_errors: ShowErrors::default(),
+            touched: false,
+            blurred: false,
+            submitted: false,
         }
     }
+    /// Starts the field from `val`. With an asynchronous validator the field has no value until
+    /// [`Self::check_initial`] checked it.
     pub fn with_value(mut self, val: T) -> Self {
         self.value_str = val.to_string();
         self.value = Some(val);
+        self.check();
+        self.mark_unchecked();
+        self
+    }
+    /// When the reason the value is invalid is shown.
+    pub fn show_errors(mut self, show_errors: ShowErrors) -> Self {
+        self.show_errors = show_errors;
+        self
+    }
+    /// Adds a rule the value has to pass, see [`crate::validate`].
+    pub fn validate(mut self, validator: Validator<T>) -> Self {
+        self.validators.push(validator);
+        self.check();
+        self.mark_unchecked();
+        self
+    }
+    /// Adds a check run in the background once the value passes every other rule, e.g. a
+    /// lookup in a database. The field has no value until the check succeeds.
+    pub fn validate_async<F, Fut>(mut self, check: F) -> Self
+    where
+        T: Send + 'static,
+        F: Fn(T) -> Fut + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        self.validate_async = Some(Box::new(move |value, generation| {
+            Command::perform(check(value), move |result| Message::Checked {
+                generation,
+                result,
+            })
+        }));
+        self.mark_unchecked();
         self
     }
+    /// Starts the asynchronous validator on a value not checked yet, e.g. given with
+    /// [`Self::with_value`] or left unchecked while the field was hidden, if it passes every
+    /// other rule.
+    pub fn check_initial(&mut self) -> Command<Message<T>> {
+        match self.pending {
+            true => self.start_check(),
+            false => Command::none(),
+        }
+    }
+    pub fn label(&self) -> &str {
+        &self.key
+    }
+    /// The parsed value, if it passes every validator.
+    pub fn value(&self) -> Option<&T> {
+        match (&self.invalid_reason, self.pending) {
+            (None, false) => self.value.as_ref(),
+            _ => None,
+        }
+    }
+    /// Whether the input lost focus at least once.
+    pub fn is_blurred(&self) -> bool {
+        self.blurred
+    }
+    /// Shows the reason the value is invalid regardless of [`ShowErrors`], as Submit was pressed.
+    pub fn submit_attempted(&mut self) {
+        self.submitted = true;
+    }
+    /// The reason the value is invalid, if it is to be shown yet.
+    pub fn error(&self) -> Option<&str> {
+        let visible = self.submitted
+            || match self.show_errors {
+                ShowErrors::OnChange => self.touched,
+                ShowErrors::OnBlur => self.blurred,
+                ShowErrors::OnSubmit => false,
+            };
+        match (&self.invalid_reason, &self.value) {
+            _ if !visible => None,
+            (Some(reason), _) => Some(reason),
+            (None, None) => Some("Required"),
+            (None, Some(_)) => None,
+        }
+    }
+    /// Whether the asynchronous validator is still checking the value.
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+    pub fn invalid_reason(&self) -> Option<&str> {
+        self.invalid_reason.as_deref()
+    }
+    /// Takes the parsed value and the text of the input, leaving the field empty, to be moved
+    /// to another field with [`Self::set_input`].
+    pub fn take_input(&mut self) -> (Option<T>, String) {
+        let input = (self.value.take(), std::mem::take(&mut self.value_str));
+        self.invalid_reason = None;
+        let _ = self.start_check();
+        input
+    }
+    /// Shows the input taken from another field, checked against the validators of this one.
+    pub fn set_input(&mut self, (value, value_str): (Option<T>, String)) -> Command<Message<T>> {
+        self.invalid_reason = match value {
+            None if !value_str.is_empty() => Some(expected::<T>()),
+            _ => None,
+        };
+        self.value = value;
+        self.value_str = value_str;
+        self.check();
+        self.start_check()
+    }
+    /// Starts the asynchronous validator if the value passes every other rule, superseding the
+    /// check of the previous input.
+    fn start_check(&mut self) -> Command<Message<T>> {
+        self.generation += 1;
+        self.pending = false;
+        if let (None, Some(value), Some(validate_async)) =
+            (&self.invalid_reason, &self.value, &self.validate_async)
+        {
+            self.pending = true;
+            return validate_async(value.clone(), self.generation);
+        }
+        Command::none()
+    }
+    /// A value passing every other rule is pending until the asynchronous validator checked it
+    fn mark_unchecked(&mut self) {
+        self.pending =
+            self.validate_async.is_some() && self.value.is_some() && self.invalid_reason.is_none();
+    }
+    fn check(&mut self) {
+        if let Some(value) = &self.value {
+            self.invalid_reason = self
+                .validators
+                .iter()
+                .find_map(|validator| validator(value, &self.value_str).err());
+        }
+    }
     pub fn view<'a, Theme>(&'a self) -> Element<'a, Message<T>, Theme>
     where
         Theme: Catalog + 'a,
     {
-        row!(
-            text(&self.key),
-            text_input(&self.value_str, &self.value_str).on_input(|val| match val.parse::<T>() {
-                Ok(v) => Message::Value((v, val)),
-                Err(_) => Message::Input {
-                    input: val,
-                    invalid_reason: format!("Expected {}", std::any::type_name::<T>())
-                },
-            })
-        )
-        .into()
+        Grid::new(LabelPosition::default())
+            .push(text(&self.key), self.view_input())
+            .into()
+    }
+    /// Renders only the input and the reason it is invalid, leaving the label to the enclosing [`Grid`].
+    pub fn view_input<'a, Theme>(&'a self) -> Element<'a, Message<T>, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        self.view_input_editable(true)
+    }
+    /// As [`Self::view_input`], the input is disabled unless `editable`.
+    pub fn view_input_editable<'a, Theme>(
+        &'a self,
+        editable: bool,
+    ) -> Element<'a, Message<T>, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        let on_input = |val: String| match val.parse::<T>() {
+            Ok(v) => Message::Value((v, val)),
+            Err(_) => Message::Input {
+                input: val,
+                invalid_reason: expected::<T>(),
+            },
+        };
+        let input = text_input(&self.value_str, &self.value_str)
+            .on_input_maybe(editable.then_some(on_input));
+        let input = OnBlur::new(input, Message::Blur);
+        match self.pending {
+            true => column![input, text("Checking…").size(12)].spacing(2).into(),
+            false => with_error(input, self.error()),
+        }
     }
     pub fn update(&mut self, message: Message<T>) -> Command<Message<T>> {
         match message {
@@ -71,14 +260,33 @@ where
                 invalid_reason,
             } => {
                 self.value_str = input;
+                self.value = None;
+                self.touched = true;
                 self.invalid_reason = Some(invalid_reason);
+                return self.start_check();
             }
             Message::Value((v, val)) => {
                 self.value_str = val;
                 self.value = Some(v);
+                self.touched = true;
                 self.invalid_reason = None;
+                self.check();
+                return self.start_check();
+            }
+            Message::Checked { generation, result } => {
+                // A newer input superseded the one checked
+                if generation == self.generation {
+                    self.pending = false;
+                    self.invalid_reason = result.err();
+                }
             }
+            Message::Blur => self.blurred = true,
         }
         Command::none()
     }
 }
+
+/// The reason an input does not parse as a `T`.
+fn expected<T>() -> String {
+    format!("Expected {}", std::any::type_name::<T>())
+}
@@ -0,0 +1,421 @@
+use std::borrow::Cow;
+
+use iced::{
+    advanced::{
+        layout, mouse, overlay, renderer,
+        widget::{Id, Operation, Tree},
+        Clipboard, Layout, Shell, Widget,
+    },
+    event, Element, Event, Length, Point, Rectangle, Size, Vector,
+};
+
+/// Where the label of a row is placed relative to its input.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LabelPosition {
+    /// Labels form a left-aligned column next to the inputs.
+    #[default]
+    Left,
+    /// Labels form a right-aligned column next to the inputs.
+    Right,
+    /// Labels are stacked on top of their inputs.
+    Top,
+}
+
+/// Picks how many columns a [`Grid`] uses for the available width.
+///
+/// Each breakpoint is the minimum width at which one more column is added,
+/// so `Breakpoints::new(&[800.0, 1400.0])` gives one column below 800,
+/// two up to 1400 and three beyond that.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakpoints(Vec<f32>);
+
+impl Breakpoints {
+    pub fn new(breakpoints: &[f32]) -> Self {
+        let mut breakpoints = breakpoints.to_vec();
+        breakpoints.sort_by(f32::total_cmp);
+        Self(breakpoints)
+    }
+    pub fn columns(&self, width: f32) -> usize {
+        1 + self
+            .0
+            .iter()
+            .filter(|breakpoint| width >= **breakpoint)
+            .count()
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new(&[800.0, 1400.0])
+    }
+}
+
+/// A label and its input, placed by a [`Grid`].
+pub struct Row<'a, Message, Theme, Renderer> {
+    label: Element<'a, Message, Theme, Renderer>,
+    input: Element<'a, Message, Theme, Renderer>,
+    span: usize,
+    id: Option<Id>,
+}
+
+impl<'a, Message, Theme, Renderer> Row<'a, Message, Theme, Renderer> {
+    pub fn new(
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+        input: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            input: input.into(),
+            span: 1,
+            id: None,
+        }
+    }
+    /// Number of columns the row takes up, capped at [`Grid::columns`].
+    pub fn span(mut self, span: usize) -> Self {
+        self.span = span.max(1);
+        self
+    }
+    /// Identifies the row as the field `id` of its form, see [`crate::scroll::scroll_to`].
+    pub fn id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = Some(Id::new(id));
+        self
+    }
+}
+
+/// A layout of labels and inputs.
+///
+/// The label column is as wide as the widest label, so inputs line up
+/// regardless of how long each label is. Rows flow into [`Grid::columns`]
+/// columns, with each row taking up as many columns as its span.
+pub struct Grid<'a, Message, Theme, Renderer> {
+    label_position: LabelPosition,
+    columns: usize,
+    spacing: f32,
+    column_spacing: f32,
+    gutter: f32,
+    /// Alternating label and input elements, one pair per row.
+    cells: Vec<Element<'a, Message, Theme, Renderer>>,
+    spans: Vec<usize>,
+    ids: Vec<Option<Id>>,
+}
+
+impl<'a, Message, Theme, Renderer> Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    pub fn new(label_position: LabelPosition) -> Self {
+        Self {
+            label_position,
+            columns: 1,
+            spacing: 5.0,
+            column_spacing: 10.0,
+            gutter: 20.0,
+            cells: Vec::new(),
+            spans: Vec::new(),
+            ids: Vec::new(),
+        }
+    }
+    /// Number of columns the rows flow into.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+    /// Vertical space between rows.
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+    /// Horizontal space between the label column and the inputs.
+    pub fn column_spacing(mut self, column_spacing: f32) -> Self {
+        self.column_spacing = column_spacing;
+        self
+    }
+    /// Horizontal space between two columns of rows.
+    pub fn gutter(mut self, gutter: f32) -> Self {
+        self.gutter = gutter;
+        self
+    }
+    pub fn push(
+        self,
+        label: impl Into<Element<'a, Message, Theme, Renderer>>,
+        input: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        self.push_row(Row::new(label, input))
+    }
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+    pub fn push_row(mut self, row: Row<'a, Message, Theme, Renderer>) -> Self {
+        self.cells.push(row.label);
+        self.cells.push(row.input);
+        self.spans.push(row.span);
+        self.ids.push(row.id);
+        self
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Grid<'a, Message, Theme, Renderer>
+where
+    Renderer: renderer::Renderer,
+{
+    fn children(&self) -> Vec<Tree> {
+        self.cells.iter().map(Tree::new).collect()
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&self.cells);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Shrink)
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let max_width = limits.max().width;
+        let unbounded = layout::Limits::new(Size::ZERO, Size::new(max_width, f32::INFINITY));
+        // Without a width to divide there is nothing to reflow into
+        let columns = if max_width.is_finite() {
+            self.columns
+        } else {
+            1
+        };
+        let cell_width = (max_width - (columns - 1) as f32 * self.gutter).max(0.0) / columns as f32;
+
+        let (labels, inputs): (Vec<_>, Vec<_>) = self
+            .cells
+            .iter()
+            .zip(tree.children.iter_mut())
+            .enumerate()
+            .partition(|(idx, _)| idx % 2 == 0);
+        let labels: Vec<_> = labels
+            .into_iter()
+            .map(|(_, (label, tree))| label.as_widget().layout(tree, renderer, &unbounded))
+            .collect();
+        let label_width = labels
+            .iter()
+            .map(|node| node.size().width)
+            .fold(0.0, f32::max);
+        let input_x = match self.label_position {
+            LabelPosition::Top => 0.0,
+            LabelPosition::Left | LabelPosition::Right => label_width + self.column_spacing,
+        };
+
+        // Lay out every row relative to its own cell, then place the cells
+        // line by line once the height of each line is known.
+        let mut rows = Vec::with_capacity(self.spans.len());
+        for ((label, (_, (input, tree))), span) in labels.into_iter().zip(inputs).zip(&self.spans) {
+            let span = (*span).min(columns);
+            let width = span as f32 * cell_width + (span - 1) as f32 * self.gutter;
+            let input_limits = layout::Limits::new(
+                Size::ZERO,
+                Size::new((width - input_x).max(0.0), f32::INFINITY),
+            );
+            let input = input.as_widget().layout(tree, renderer, &input_limits);
+            let label_size = label.size();
+            let input_size = input.size();
+            let (label_position, input_position, height) = match self.label_position {
+                LabelPosition::Top => (
+                    Point::ORIGIN,
+                    Point::new(0.0, label_size.height + self.spacing),
+                    label_size.height + self.spacing + input_size.height,
+                ),
+                LabelPosition::Left | LabelPosition::Right => {
+                    let height = label_size.height.max(input_size.height);
+                    let label_x = match self.label_position {
+                        LabelPosition::Right => label_width - label_size.width,
+                        _ => 0.0,
+                    };
+                    (
+                        Point::new(label_x, (height - label_size.height) / 2.0),
+                        Point::new(input_x, (height - input_size.height) / 2.0),
+                        height,
+                    )
+                }
+            };
+            rows.push((label, label_position, input, input_position, span, height));
+        }
+
+        let mut children = Vec::with_capacity(self.cells.len());
+        let mut content_width = 0.0f32;
+        let mut y = 0.0;
+        let mut column = 0;
+        let mut line_height = 0.0f32;
+        for (label, label_position, input, input_position, span, height) in rows {
+            if column + span > columns {
+                y += line_height + self.spacing;
+                column = 0;
+                line_height = 0.0;
+            }
+            let x = match column {
+                0 => 0.0,
+                column => column as f32 * (cell_width + self.gutter),
+            };
+            let offset = Vector::new(x, y);
+            content_width = content_width
+                .max(offset.x + label_position.x + label.size().width)
+                .max(offset.x + input_position.x + input.size().width);
+            children.push(label.move_to(label_position + offset));
+            children.push(input.move_to(input_position + offset));
+            line_height = line_height.max(height);
+            column += span;
+        }
+        let height = y + line_height;
+        let width = if max_width.is_finite() {
+            max_width
+        } else {
+            content_width
+        };
+        layout::Node::with_children(
+            limits.resolve(Length::Fill, Length::Shrink, Size::new(width, height)),
+            children,
+        )
+    }
+
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation<Message>,
+    ) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            let mut cells = self
+                .cells
+                .iter()
+                .zip(&mut tree.children)
+                .zip(layout.children());
+            for id in &self.ids {
+                let (
+                    Some(((label, label_tree), label_layout)),
+                    Some(((input, input_tree), input_layout)),
+                ) = (cells.next(), cells.next())
+                else {
+                    break;
+                };
+                // Each row is a container of its own, so operations can find
+                // a field by its id along with where it was laid out.
+                let bounds = label_layout.bounds().union(&input_layout.bounds());
+                operation.container(id.as_ref(), bounds, &mut |operation| {
+                    label
+                        .as_widget()
+                        .operate(label_tree, label_layout, renderer, operation);
+                    input
+                        .as_widget()
+                        .operate(input_tree, input_layout, renderer, operation);
+                });
+            }
+        });
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) -> event::Status {
+        self.cells
+            .iter_mut()
+            .zip(&mut tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child.as_widget_mut().on_event(
+                    state,
+                    event.clone(),
+                    layout,
+                    cursor,
+                    renderer,
+                    clipboard,
+                    shell,
+                    viewport,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.cells
+            .iter()
+            .zip(&tree.children)
+            .zip(layout.children())
+            .map(|((child, state), layout)| {
+                child
+                    .as_widget()
+                    .mouse_interaction(state, layout, cursor, viewport, renderer)
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        for ((child, state), layout) in self.cells.iter().zip(&tree.children).zip(layout.children())
+        {
+            child
+                .as_widget()
+                .draw(state, renderer, theme, style, layout, cursor, viewport);
+        }
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        overlay::from_children(&mut self.cells, tree, layout, renderer, translation)
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Grid<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(grid: Grid<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Breakpoints;
+
+    #[test]
+    fn columns() {
+        let breakpoints = Breakpoints::new(&[1400.0, 800.0]);
+        assert_eq!(breakpoints.columns(0.0), 1);
+        assert_eq!(breakpoints.columns(799.9), 1);
+        assert_eq!(breakpoints.columns(800.0), 2);
+        assert_eq!(breakpoints.columns(1400.0), 3);
+        assert_eq!(Breakpoints::new(&[]).columns(5000.0), 1);
+    }
+}
@@ -1,11 +1,46 @@
-use iced::widget::{button, pick_list, text, text_input, toggler};
+use iced::{
+    widget::{
+        button, checkbox, combo_box, pick_list, radio, scrollable, text, text_input, toggler,
+    },
+    Color,
+};
 
+pub mod choice;
+#[cfg(feature = "bitflags")]
+pub mod flags;
+pub mod focus;
+mod form;
 pub mod form_field;
+pub mod layout;
+pub mod nav;
+pub mod option;
+pub mod preselect;
+pub mod scroll;
+pub mod search;
+pub mod set;
+pub mod validate;
+
+pub use form::{EnumForm, Form};
 
 #[derive(Default)]
-pub struct Style {}
+pub struct Style {
+    /// Background behind the parts of labels matching a search
+    pub highlight: Color,
+    /// Badge of a nested form that builds
+    pub valid: Color,
+    /// Badge of a nested form that does not build
+    pub invalid: Color,
+}
 pub trait Catalog:
-    text::Catalog + text_input::Catalog + toggler::Catalog + button::Catalog + pick_list::Catalog
+    text::Catalog
+    + text_input::Catalog
+    + toggler::Catalog
+    + button::Catalog
+    + checkbox::Catalog
+    + combo_box::Catalog
+    + pick_list::Catalog
+    + radio::Catalog
+    + scrollable::Catalog
 {
     /// The item class of this [`Catalog`].
     type Class<'a>;
@@ -13,6 +48,13 @@ pub trait Catalog:
     /// The default class produced by this [`Catalog`].
     fn default<'a>() -> <Self as Catalog>::Class<'a>;
     fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style;
+    /// The class of a button of a segmented [`choice::Picker`], `selected` for the option picked.
+    ///
+    /// Every button gets the default button class of the theme unless overridden, custom themes
+    /// override it to set the option picked apart.
+    fn segment<'a>(_selected: bool) -> <Self as button::Catalog>::Class<'a> {
+        <Self as button::Catalog>::default()
+    }
 }
 
 pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
@@ -20,9 +62,24 @@ impl Catalog for iced::Theme {
     type Class<'a> = StyleFn<'a, Self>;
 
     fn default<'a>() -> <Self as Catalog>::Class<'a> {
-        Box::new(|_| Style::default())
+        Box::new(default)
     }
     fn style(&self, class: &<Self as Catalog>::Class<'_>) -> Style {
         class(self)
     }
+    fn segment<'a>(selected: bool) -> <Self as button::Catalog>::Class<'a> {
+        match selected {
+            true => Box::new(button::primary),
+            false => Box::new(button::secondary),
+        }
+    }
+}
+
+pub fn default(theme: &iced::Theme) -> Style {
+    let palette = theme.extended_palette();
+    Style {
+        highlight: palette.primary.weak.color,
+        valid: palette.success.base.color,
+        invalid: palette.danger.base.color,
+    }
 }
@@ -0,0 +1,244 @@
+use iced::{
+    advanced::{layout, mouse, renderer, widget::Tree, Layout, Widget},
+    border::Radius,
+    widget::{column, mouse_area, row, text, Column, Space},
+    Alignment, Border, Element, Length, Rectangle, Size,
+};
+
+use crate::{search::Highlight, Catalog};
+
+/// A nested form in the navigation tree of a form.
+#[derive(Clone, Debug)]
+pub struct Node {
+    pub label: String,
+    /// Dotted path of the nested form from the root, empty for the root itself
+    pub path: String,
+    /// Whether the nested form and every form nested within it builds
+    pub valid: bool,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn new(label: &str, path: &str, valid: bool, children: Vec<Node>) -> Self {
+        Self {
+            label: label.to_string(),
+            path: path.to_string(),
+            valid: valid && children.iter().all(|child| child.valid),
+            children,
+        }
+    }
+    /// The nodes leading from this node to the one at `path`, both included.
+    fn trail(&self, path: &str) -> Vec<&Node> {
+        if self.path == path {
+            return vec![self];
+        }
+        self.children
+            .iter()
+            .map(|child| child.trail(path))
+            .find(|trail| !trail.is_empty())
+            .map(|mut trail| {
+                trail.insert(0, self);
+                trail
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Appends `field` to the dotted `path`.
+pub fn join(path: &str, field: &str) -> String {
+    match (path, field) {
+        ("", field) => field.to_string(),
+        (path, "") => path.to_string(),
+        (path, field) => format!("{path}.{field}"),
+    }
+}
+
+/// The dotted `path` relative to `base`, `None` unless `path` lies strictly within `base`, an
+/// empty `base` holds every path.
+pub fn relative<'p>(path: &'p str, base: &str) -> Option<&'p str> {
+    match base {
+        "" => Some(path),
+        base => path.strip_prefix(base)?.strip_prefix('.'),
+    }
+}
+
+/// Splits the first field off a dotted `path`.
+pub fn split(path: &str) -> (&str, &str) {
+    path.split_once('.').unwrap_or((path, ""))
+}
+
+/// The tree of nested forms, indented by depth with a validity badge each.
+pub fn sidebar<'a, Message, Theme>(
+    root: &Node,
+    selected: &str,
+    on_select: impl Fn(String) -> Message + Copy + 'a,
+) -> Element<'a, Message, Theme>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+{
+    fn push<'a, Message, Theme>(
+        entries: Column<'a, Message, Theme>,
+        node: &Node,
+        depth: u16,
+        selected: &str,
+        on_select: impl Fn(String) -> Message + Copy + 'a,
+    ) -> Column<'a, Message, Theme>
+    where
+        Message: Clone + 'a,
+        Theme: Catalog + 'a,
+    {
+        let label: Element<'a, Message, Theme> = if node.path == selected {
+            Highlight::new(text(node.label.clone())).into()
+        } else {
+            text(node.label.clone()).into()
+        };
+        let entry = mouse_area(
+            row![
+                Space::with_width(Length::Fixed(f32::from(depth) * 16.0)),
+                Badge::new(node.valid),
+                label,
+            ]
+            .spacing(5)
+            .align_items(Alignment::Center),
+        )
+        .on_press(on_select(node.path.clone()));
+        node.children
+            .iter()
+            .fold(entries.push(entry), |entries, child| {
+                push(entries, child, depth + 1, selected, on_select)
+            })
+    }
+    push(column![].spacing(5), root, 0, selected, on_select).into()
+}
+
+/// The path from the root to the `selected` node, each step navigable.
+pub fn breadcrumbs<'a, Message, Theme>(
+    root: &Node,
+    selected: &str,
+    on_select: impl Fn(String) -> Message + 'a,
+) -> Element<'a, Message, Theme>
+where
+    Message: Clone + 'a,
+    Theme: Catalog + 'a,
+{
+    root.trail(selected)
+        .into_iter()
+        .enumerate()
+        .fold(row![].spacing(5), |crumbs, (idx, node)| {
+            let crumbs = match idx {
+                0 => crumbs,
+                _ => crumbs.push(text("/")),
+            };
+            crumbs.push(mouse_area(text(node.label.clone())).on_press(on_select(node.path.clone())))
+        })
+        .into()
+}
+
+/// A dot in the valid or invalid color of the [`Catalog`].
+struct Badge<Theme>
+where
+    Theme: Catalog,
+{
+    valid: bool,
+    class: <Theme as Catalog>::Class<'static>,
+}
+
+impl<Theme> Badge<Theme>
+where
+    Theme: Catalog,
+{
+    const SIZE: f32 = 8.0;
+
+    fn new(valid: bool) -> Self {
+        Self {
+            valid,
+            class: <Theme as Catalog>::default(),
+        }
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Badge<Theme>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fixed(Self::SIZE), Length::Fixed(Self::SIZE))
+    }
+
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(Size::new(Self::SIZE, Self::SIZE))
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let style = <Theme as Catalog>::style(theme, &self.class);
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                border: Border {
+                    radius: Radius::from(Self::SIZE / 2.0),
+                    ..Border::default()
+                },
+                ..renderer::Quad::default()
+            },
+            if self.valid {
+                style.valid
+            } else {
+                style.invalid
+            },
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Badge<Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer,
+{
+    fn from(badge: Badge<Theme>) -> Self {
+        Self::new(badge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{join, relative, split};
+
+    #[test]
+    fn join_paths() {
+        assert_eq!(join("", "proxy"), "proxy");
+        assert_eq!(join("proxy", ""), "proxy");
+        assert_eq!(join("config", "proxy.port"), "config.proxy.port");
+    }
+
+    #[test]
+    fn split_paths() {
+        assert_eq!(split("config.proxy.port"), ("config", "proxy.port"));
+        assert_eq!(split("config"), ("config", ""));
+        assert_eq!(split(""), ("", ""));
+    }
+
+    #[test]
+    fn relative_paths() {
+        assert_eq!(relative("config.name", ""), Some("config.name"));
+        assert_eq!(relative("config.name", "config"), Some("name"));
+        assert_eq!(relative("config", "config"), None);
+        assert_eq!(relative("configs.name", "config"), None);
+        assert_eq!(relative("proxy.port", "config"), None);
+    }
+}
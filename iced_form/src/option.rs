@@ -0,0 +1,190 @@
+use iced::{widget::column, Command, Element};
+
+use crate::{
+    choice::{Choice, OrNone, Picker, Variants},
+    nav::Node,
+    search,
+    validate::{FormErrors, PathErrors},
+    Catalog, EnumForm, Form,
+};
+
+#[derive(Clone, Debug)]
+pub enum Message<K, M> {
+    /// `None` or one of the variants was picked
+    Select(OrNone<K>),
+    /// Input of the fields of the variant picked
+    Form(M),
+}
+
+/// The form of an `Option<Enum>` field, picking `None` along with the variants of the enum in the
+/// same picker.
+pub struct OptionForm<F: EnumForm> {
+    selected: OrNone<F::Kind>,
+    choice: Choice<OrNone<F::Kind>>,
+    /// Holds the variant picked, its own picker is left out of the view
+    form: F,
+    readonly: bool,
+}
+
+impl<F> Default for OptionForm<F>
+where
+    F: EnumForm + Default,
+{
+    fn default() -> Self {
+        let form = F::default();
+        let mut option = Self {
+            selected: OrNone::None(String::new()),
+            choice: Choice::new(form.picker(), []),
+            form,
+            readonly: false,
+        };
+        option.set_none_label("None");
+        option
+    }
+}
+
+impl<F: EnumForm> OptionForm<F> {
+    /// Labels the `None` choice, e.g. "Inherit"
+    #[doc(hidden)]
+    pub fn set_none_label(&mut self, label: &str) {
+        let options = OrNone::options(label, F::Kind::variants());
+        self.choice = Choice::new(self.choice.picker(), options);
+        if let OrNone::None(none) = &mut self.selected {
+            *none = label.to_string();
+        }
+    }
+    #[doc(hidden)]
+    pub fn set_picker(&mut self, picker: Picker) {
+        self.choice.set_picker(picker);
+    }
+    fn is_none(&self) -> bool {
+        matches!(self.selected, OrNone::None(_))
+    }
+    fn view_choice<'a, Theme>(&'a self) -> Element<'a, Message<F::Kind, F::Message>, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        self.choice
+            .view(!self.readonly, Some(&self.selected), Message::Select)
+    }
+}
+
+impl<F> Form for OptionForm<F>
+where
+    F: EnumForm + Default,
+{
+    type Value = Option<F::Value>;
+    type Message = Message<F::Kind, F::Message>;
+
+    fn from_value(value: Option<F::Value>) -> Self {
+        let mut option = Self::default();
+        if let Some(value) = value {
+            option.form = F::from_value(value);
+            if let Some(kind) = option.form.kind() {
+                option.selected = OrNone::Some(kind);
+            }
+        }
+        option
+    }
+    fn build(&self) -> Option<Option<F::Value>> {
+        match self.is_none() {
+            true => Some(None),
+            false => self.form.build().map(Some),
+        }
+    }
+    fn update(&mut self, message: Self::Message) -> Command<Self::Message> {
+        match message {
+            Message::Select(_) if self.readonly => Command::none(),
+            Message::Select(selected) => {
+                let command = match &selected {
+                    OrNone::Some(kind) => self.form.pick(kind.clone()).map(Message::Form),
+                    OrNone::None(_) => Command::none(),
+                };
+                self.selected = selected;
+                command
+            }
+            Message::Form(message) => self.form.update(message).map(Message::Form),
+        }
+    }
+    fn view_nested<'a, Theme>(&'a self) -> Element<'a, Self::Message, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        let mut content = column![self.view_choice()];
+        if let Some(fields) = self.form.view_fields().filter(|_| !self.is_none()) {
+            content = content.push(fields.map(Message::Form));
+        }
+        content.into()
+    }
+    fn view_filtered<'a, Theme>(&'a self, query: &str) -> Option<Element<'a, Self::Message, Theme>>
+    where
+        Theme: Catalog + 'a,
+    {
+        if query.trim().is_empty() || self.is_none() {
+            return self
+                .choice
+                .options()
+                .iter()
+                .any(|option| search::matches(query, &[&option.to_string()]))
+                .then(|| self.view_nested());
+        }
+        let fields = self.form.view_fields_filtered(query)?;
+        Some(column![self.view_choice(), fields.map(Message::Form)].into())
+    }
+    fn view_at<'a, Theme>(&'a self, path: &str) -> Option<Element<'a, Self::Message, Theme>>
+    where
+        Theme: Catalog + 'a,
+    {
+        if path.is_empty() {
+            return Some(self.view_nested());
+        }
+        if self.is_none() {
+            return None;
+        }
+        self.form.view_at(path).map(|form| form.map(Message::Form))
+    }
+    /// The entry of the inner form whether `None` is picked or not, without its nested forms
+    /// while `None` is
+    fn nav_tree(&self, label: &str, path: &str) -> Option<Node> {
+        let node = self.form.nav_tree(label, path)?;
+        match self.is_none() {
+            true => Some(Node::new(label, path, true, Vec::new())),
+            false => Some(node),
+        }
+    }
+    fn init(&mut self) -> Command<Self::Message> {
+        self.form.init().map(Message::Form)
+    }
+    fn set_errors(&mut self, errors: PathErrors) {
+        self.form.set_errors(errors);
+    }
+    fn apply_errors(&mut self, errors: FormErrors) {
+        self.form.apply_errors(errors);
+    }
+    fn is_edit(&self, message: &Self::Message) -> bool {
+        match message {
+            Message::Select(_) => true,
+            Message::Form(message) => self.form.is_edit(message),
+        }
+    }
+    fn is_pending(&self) -> bool {
+        !self.is_none() && self.form.is_pending()
+    }
+    fn submit_attempted(&mut self) {
+        self.form.submit_attempted();
+    }
+    fn first_invalid(&self) -> Option<String> {
+        match self.is_none() {
+            true => None,
+            false => self.form.first_invalid(),
+        }
+    }
+    /// Makes the field of the variant picked at the dotted `path` read-only or editable again, an
+    /// empty path also keeps the choice from changing
+    fn set_readonly(&mut self, path: &str, readonly: bool) {
+        if path.is_empty() {
+            self.readonly = readonly;
+        }
+        self.form.set_readonly(path, readonly);
+    }
+}
@@ -0,0 +1,38 @@
+//! Lets the derive preselect the `Default` of an enum, when it has one.
+//!
+//! Whether a type implements `Default` is not known to a proc macro, the generated forms call
+//! `(&Probe::<T>::new()).default_value()` with both traits in scope instead. The method taking
+//! `Probe<T>` by reference wins when `T: Default`, the one on `&Probe<T>` is the fallback.
+use std::marker::PhantomData;
+
+#[doc(hidden)]
+pub struct Probe<T>(PhantomData<T>);
+
+impl<T> Probe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait WithDefault<T> {
+    fn default_value(&self) -> Option<T>;
+}
+
+impl<T: Default> WithDefault<T> for Probe<T> {
+    fn default_value(&self) -> Option<T> {
+        Some(T::default())
+    }
+}
+
+#[doc(hidden)]
+pub trait WithoutDefault<T> {
+    fn default_value(&self) -> Option<T>;
+}
+
+impl<T> WithoutDefault<T> for &Probe<T> {
+    fn default_value(&self) -> Option<T> {
+        None
+    }
+}
@@ -0,0 +1,178 @@
+use iced::{
+    advanced::widget::{
+        operation::{self, Outcome},
+        Id, Operation,
+    },
+    widget::scrollable::{self, AbsoluteOffset, RelativeOffset},
+    Command, Rectangle,
+};
+
+/// Scrolls the [`scrollable`] with the given id so the field at `path` is at the top.
+///
+/// `path` is the dotted path of the field, e.g. `sub_config.name`, matching the
+/// ids given to each [`crate::layout::Row`] by the generated forms. An empty path, standing for
+/// the form itself, scrolls back to the top.
+pub fn scroll_to<T>(scrollable: scrollable::Id, path: &str) -> Command<T>
+where
+    T: Send + 'static,
+{
+    if path.is_empty() {
+        return scrollable::snap_to(scrollable, RelativeOffset::START);
+    }
+    Command::widget(ScrollToField {
+        scrollable: scrollable.into(),
+        path: path
+            .split('.')
+            .map(|segment| Id::new(segment.to_string()))
+            .collect(),
+        inside: false,
+        depth: 0,
+        scrollable_y: None,
+        field_y: None,
+    })
+}
+
+struct ScrollToField {
+    scrollable: Id,
+    path: Vec<Id>,
+    inside: bool,
+    /// Number of path segments matched by the containers we are currently in
+    depth: usize,
+    scrollable_y: Option<f32>,
+    field_y: Option<f32>,
+}
+
+impl<T> Operation<T> for ScrollToField
+where
+    T: 'static,
+{
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        if self.field_y.is_some() {
+            return;
+        }
+        if id == Some(&self.scrollable) {
+            self.scrollable_y = Some(bounds.y);
+            self.inside = true;
+            operate_on_children(self);
+            self.inside = false;
+            return;
+        }
+        if self.inside && id.is_some() && id == self.path.get(self.depth) {
+            if self.depth + 1 == self.path.len() {
+                self.field_y = Some(bounds.y);
+                return;
+            }
+            self.depth += 1;
+            operate_on_children(self);
+            self.depth -= 1;
+            return;
+        }
+        operate_on_children(self);
+    }
+
+    fn finish(&self) -> Outcome<T> {
+        match (self.scrollable_y, self.field_y) {
+            (Some(scrollable_y), Some(field_y)) => {
+                Outcome::Chain(Box::new(operation::scrollable::scroll_to(
+                    self.scrollable.clone(),
+                    AbsoluteOffset {
+                        x: 0.0,
+                        y: field_y - scrollable_y,
+                    },
+                )))
+            }
+            _ => Outcome::None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iced::{
+        advanced::widget::{operation::Outcome, Id, Operation},
+        Rectangle,
+    };
+
+    use super::ScrollToField;
+
+    struct Node {
+        id: Option<&'static str>,
+        y: f32,
+        children: Vec<Node>,
+    }
+
+    fn node(id: Option<&'static str>, y: f32, children: Vec<Node>) -> Node {
+        Node { id, y, children }
+    }
+
+    fn walk(operation: &mut dyn Operation<()>, node: &Node) {
+        let id = node.id.map(Id::new);
+        let bounds = Rectangle {
+            y: node.y,
+            ..Rectangle::default()
+        };
+        operation.container(id.as_ref(), bounds, &mut |operation| {
+            for child in &node.children {
+                walk(operation, child);
+            }
+        });
+    }
+
+    fn scroll(path: &str, tree: &Node) -> ScrollToField {
+        let mut operation = ScrollToField {
+            scrollable: Id::new("form"),
+            path: path
+                .split('.')
+                .map(|segment| Id::new(segment.to_string()))
+                .collect(),
+            inside: false,
+            depth: 0,
+            scrollable_y: None,
+            field_y: None,
+        };
+        walk(&mut operation, tree);
+        operation
+    }
+
+    #[test]
+    fn finds_nested_field() {
+        let tree = node(
+            None,
+            0.0,
+            vec![
+                node(Some("proxy"), 10.0, vec![]),
+                node(
+                    Some("form"),
+                    100.0,
+                    vec![
+                        node(Some("proxy"), 150.0, vec![]),
+                        node(
+                            Some("network"),
+                            300.0,
+                            vec![node(None, 300.0, vec![node(Some("proxy"), 340.0, vec![])])],
+                        ),
+                    ],
+                ),
+            ],
+        );
+        let operation = scroll("network.proxy", &tree);
+        assert_eq!(operation.scrollable_y, Some(100.0));
+        assert_eq!(operation.field_y, Some(340.0));
+        assert!(matches!(
+            Operation::<()>::finish(&operation),
+            Outcome::Chain(_)
+        ));
+
+        let operation = scroll("proxy", &tree);
+        assert_eq!(operation.field_y, Some(150.0));
+
+        let operation = scroll("network.port", &tree);
+        assert_eq!(operation.field_y, None);
+        assert!(matches!(Operation::<()>::finish(&operation), Outcome::None));
+    }
+}
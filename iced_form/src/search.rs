@@ -0,0 +1,199 @@
+use std::ops::Range;
+
+use iced::{
+    advanced::{layout, mouse, renderer, widget::Tree, Layout, Widget},
+    widget::{row, text},
+    Element, Length, Rectangle, Size,
+};
+
+use crate::Catalog;
+
+/// Whether a field described by `haystacks` (its label, path, doc comment, ..) matches `query`.
+///
+/// An empty query matches every field.
+pub fn matches(query: &str, haystacks: &[&str]) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty()
+        || haystacks
+            .iter()
+            .any(|haystack| haystack.to_lowercase().contains(&query))
+}
+
+/// The query to hand to the nested form of `field`.
+///
+/// A query such as `sub_config.name` is narrowed down to `name` once inside `sub_config`.
+pub fn descend<'q>(query: &'q str, field: &str) -> &'q str {
+    query
+        .trim()
+        .strip_prefix(field)
+        .and_then(|rest| rest.strip_prefix('.'))
+        .unwrap_or(query)
+}
+
+/// A label with the part matching `query` highlighted.
+pub fn label<'a, Message, Theme>(label: &str, query: &str) -> Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    match find(label, query) {
+        Some(range) => row![
+            text(label[..range.start].to_string()),
+            Highlight::new(text(label[range.clone()].to_string())),
+            text(label[range.end..].to_string()),
+        ]
+        .into(),
+        None => text(label.to_string()).into(),
+    }
+}
+
+fn find(label: &str, query: &str) -> Option<Range<usize>> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    // Lowercasing may change the byte length of a char outside of ASCII, the offset of each char
+    // in the lowercase label is kept along with its offset in `label`
+    let mut lowercase = String::with_capacity(label.len());
+    let mut offsets = Vec::new();
+    for (idx, c) in label.char_indices() {
+        offsets.push((lowercase.len(), idx));
+        lowercase.extend(c.to_lowercase());
+    }
+    offsets.push((lowercase.len(), label.len()));
+    let start = lowercase.find(&query)?;
+    // A match starting or ending within the lowercase form of a char is not highlighted
+    let to_label = |offset: usize| {
+        offsets
+            .iter()
+            .find(|(lower, _)| *lower == offset)
+            .map(|(_, idx)| *idx)
+    };
+    Some(to_label(start)?..to_label(start + query.len())?)
+}
+
+/// Draws the highlight background of the [`Catalog`] behind its content.
+pub(crate) struct Highlight<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    content: Element<'a, Message, Theme, Renderer>,
+    class: <Theme as Catalog>::Class<'a>,
+}
+
+impl<'a, Message, Theme, Renderer> Highlight<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+{
+    pub(crate) fn new(content: impl Into<Element<'a, Message, Theme, Renderer>>) -> Self {
+        Self {
+            content: content.into(),
+            class: <Theme as Catalog>::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Highlight<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: renderer::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let content = self
+            .content
+            .as_widget()
+            .layout(&mut tree.children[0], renderer, limits);
+        layout::Node::with_children(content.size(), vec![content])
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: layout.bounds(),
+                ..renderer::Quad::default()
+            },
+            <Theme as Catalog>::style(theme, &self.class).highlight,
+        );
+        if let Some(content_layout) = layout.children().next() {
+            self.content.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                content_layout,
+                cursor,
+                viewport,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Highlight<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: renderer::Renderer + 'a,
+{
+    fn from(highlight: Highlight<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(highlight)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{descend, find};
+
+    #[test]
+    fn find_ignores_case() {
+        assert_eq!(find("Sub Config", "config"), Some(4..10));
+        assert_eq!(find("Sub Config", " SUB "), Some(0..3));
+        assert_eq!(find("Sub Config", "name"), None);
+        assert_eq!(find("Sub Config", ""), None);
+    }
+
+    #[test]
+    fn find_maps_offsets_back() {
+        // `İ` lowercases to more bytes than it takes, the Kelvin sign to fewer
+        assert_eq!(find("İd", "d"), Some(2..3));
+        assert_eq!(find("İİ\u{212a}", "k"), Some(4..7));
+        assert_eq!(find("İİ\u{212a}", "İk"), Some(2..7));
+        // A match within the lowercase form of `İ` is not highlighted
+        assert_eq!(find("İd", "\u{307}d"), None);
+    }
+
+    #[test]
+    fn descend_narrows_paths() {
+        assert_eq!(descend("sub_config.name", "sub_config"), "name");
+        assert_eq!(descend(" sub_config.name ", "sub_config"), "name");
+        assert_eq!(descend("name", "sub_config"), "name");
+        assert_eq!(descend("sub_config", "sub_config"), "sub_config");
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    fmt::{Debug, Display},
+    marker::PhantomData,
+};
+
+use iced::{Command, Element};
+
+use crate::{
+    choice::{Selection, Variants},
+    search, Catalog, Form,
+};
+
+#[derive(Clone, Debug)]
+pub enum Message<E> {
+    Toggled(E, bool),
+    /// Checks or unchecks every variant
+    All(bool),
+}
+
+/// The form of a `HashSet<E>`, `BTreeSet<E>` or `Vec<E>` field of a unit enum `E`, a checkbox per
+/// variant of [`Variants::variants`].
+///
+/// The collection is built from the checked variants in the order they are listed, a `Vec` given
+/// to [`Form::from_value`] comes back sorted that way with its duplicates removed. Values that are
+/// not listed, e.g. skipped variants, stay checked, read-only, and are built after the listed ones.
+pub struct SetForm<E, C> {
+    readonly: bool,
+    selection: Selection<E>,
+    collection: PhantomData<C>,
+}
+
+impl<E, C> Default for SetForm<E, C>
+where
+    E: Variants + Display + PartialEq + Clone + 'static,
+{
+    fn default() -> Self {
+        Self {
+            readonly: false,
+            selection: Selection::new(E::variants()),
+            collection: PhantomData,
+        }
+    }
+}
+
+impl<E, C> SetForm<E, C>
+where
+    E: Variants + Display + PartialEq + Clone + 'static,
+{
+    /// Between `min` and `max` variants have to be checked for the form to build
+    #[doc(hidden)]
+    pub fn set_bounds(&mut self, min: Option<usize>, max: Option<usize>) {
+        self.selection.set_bounds(min, max);
+    }
+    /// Shows "All" and "None" buttons
+    #[doc(hidden)]
+    pub fn set_shortcuts(&mut self, shortcuts: bool) {
+        self.selection.set_shortcuts(shortcuts);
+    }
+}
+
+impl<E, C> Form for SetForm<E, C>
+where
+    E: Variants + Display + Debug + PartialEq + Clone + Send + 'static,
+    C: FromIterator<E> + IntoIterator<Item = E>,
+{
+    type Value = C;
+    type Message = Message<E>;
+
+    fn from_value(value: C) -> Self {
+        let mut form = Self::default();
+        for val in value {
+            form.selection.pick(val);
+        }
+        form
+    }
+    /// The checked variants, in the order they are listed, then those that are not listed
+    fn build(&self) -> Option<C> {
+        self.selection.build()
+    }
+    fn update(&mut self, message: Message<E>) -> Command<Message<E>> {
+        if !self.readonly {
+            match message {
+                Message::Toggled(val, checked) => self.selection.toggle(&val, checked),
+                Message::All(checked) => self.selection.set_all(checked),
+            }
+        }
+        Command::none()
+    }
+    fn view_nested<'a, Theme>(&'a self) -> Element<'a, Message<E>, Theme>
+    where
+        Theme: Catalog + 'a,
+    {
+        self.selection
+            .view(!self.readonly, Message::Toggled, Message::All)
+    }
+    fn view_filtered<'a, Theme>(&'a self, query: &str) -> Option<Element<'a, Message<E>, Theme>>
+    where
+        Theme: Catalog + 'a,
+    {
+        E::variants()
+            .iter()
+            .any(|option| search::matches(query, &[&option.to_string()]))
+            .then(|| self.view_nested())
+    }
+    /// Keeps the checkboxes from changing, the path is ignored as the enum has no fields
+    fn set_readonly(&mut self, _path: &str, readonly: bool) {
+        self.readonly = readonly;
+    }
+    /// Empty when too few or too many variants are checked
+    fn first_invalid(&self) -> Option<String> {
+        self.selection.error().map(|_| String::new())
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt::Display;
+
+use iced::{
+    widget::{column, text},
+    Element,
+};
+use regex::Regex;
+
+use crate::{nav, Catalog};
+
+#[cfg(feature = "garde")]
+pub mod garde;
+#[cfg(feature = "validator")]
+pub mod validator;
+
+/// A rule checked by a [`crate::form_field::FormField`] against the parsed value and the raw input.
+///
+/// Returns the reason shown below the input when the rule fails.
+pub type Validator<T> = Box<dyn Fn(&T, &str) -> Result<(), String>>;
+
+/// Whether an error is shown below its field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ShowErrors {
+    /// As soon as the field is edited.
+    OnChange,
+    /// Once the field loses focus for the first time.
+    #[default]
+    OnBlur,
+    /// Only after Submit is pressed.
+    OnSubmit,
+}
+
+/// Errors of a cross-field validator, each naming the field it is shown below.
+///
+/// Returned by the function given to `#[form(validate = path::to::fn)]` on a struct or enum
+/// variant, e.g. `vec![("tax", "Must be below the cost".to_string())]`.
+pub type FieldErrors = Vec<(&'static str, String)>;
+
+/// Errors keyed by the dotted path of the field relative to the form, e.g. `proxy.port` or
+/// `hosts[0].name`.
+pub type PathErrors = Vec<(String, String)>;
+
+/// Errors computed outside the form, e.g. by a backend after submit, keyed by the dotted path
+/// of the field. Placed on the forms with `XForm::apply_errors`.
+#[derive(Clone, Debug, Default)]
+pub struct FormErrors(PathErrors);
+
+impl FormErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Adds `error` for the field at `path`, e.g. `proxy.port`.
+    pub fn push(&mut self, path: impl Into<String>, error: impl Into<String>) {
+        self.0.push((path.into(), error.into()));
+    }
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// The errors within the nested form of `field`, relative to it.
+    pub fn nested(&self, field: &str) -> Self {
+        Self(nested(&self.0, field))
+    }
+    /// The errors not within any of the nested forms of `fields`.
+    pub fn outside(self, fields: &[&str]) -> PathErrors {
+        self.0
+            .into_iter()
+            .filter(|(path, _)| {
+                let (first, rest) = nav::split(path);
+                rest.is_empty() || !fields.contains(&first)
+            })
+            .collect()
+    }
+}
+
+impl<P, E> FromIterator<(P, E)> for FormErrors
+where
+    P: Into<String>,
+    E: Into<String>,
+{
+    fn from_iter<I: IntoIterator<Item = (P, E)>>(iter: I) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(path, error)| (path.into(), error.into()))
+                .collect(),
+        )
+    }
+}
+
+/// Removes the errors of `field` itself or of an element of it.
+pub fn clear(errors: &mut PathErrors, field: &str) {
+    errors.retain(|(path, _)| !is_field(path, field));
+}
+
+/// Removes the errors of `field`, of an element of it and of the fields nested within it, e.g.
+/// of a field hidden by `visible_if`.
+pub fn hide(errors: &mut PathErrors, field: &str) {
+    errors.retain(|(path, _)| !is_field(nav::split(path).0, field));
+}
+
+fn is_field(path: &str, field: &str) -> bool {
+    path.strip_prefix(field)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('['))
+}
+
+/// The errors of a `#[form(validate = path::to::fn)]` validator as [`PathErrors`].
+pub fn field_errors(result: Result<(), FieldErrors>) -> PathErrors {
+    result
+        .err()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(field, error)| (field.to_string(), error))
+        .collect()
+}
+
+/// The first error of `field` itself or of an element of it, e.g. `hosts[0]` for `hosts`.
+pub fn find<'e>(errors: &'e [(String, String)], field: &str) -> Option<&'e str> {
+    errors
+        .iter()
+        .find(|(path, _)| is_field(path, field))
+        .map(|(_, error)| error.as_str())
+}
+
+/// The errors within the nested form of `field`, relative to it.
+pub fn nested<'e>(
+    errors: impl IntoIterator<Item = &'e (String, String)>,
+    field: &str,
+) -> PathErrors {
+    errors
+        .into_iter()
+        .filter_map(|(path, error)| match nav::split(path) {
+            (first, rest) if first == field && !rest.is_empty() => {
+                Some((rest.to_string(), error.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Shows `error`, if any, below `input`.
+pub fn with_error<'a, Message, Theme>(
+    input: impl Into<Element<'a, Message, Theme>>,
+    error: Option<&str>,
+) -> Element<'a, Message, Theme>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+{
+    match error {
+        Some(error) => column![input.into(), text(error.to_string()).size(12)]
+            .spacing(2)
+            .into(),
+        None => input.into(),
+    }
+}
+
+/// The value lies within `min..=max`, either bound may be left open.
+pub fn range<T>(min: Option<T>, max: Option<T>) -> Validator<T>
+where
+    T: PartialOrd + Display + 'static,
+{
+    Box::new(move |value, _| match (&min, &max) {
+        (Some(min), _) if value < min => Err(format!("Must be at least {min}")),
+        (_, Some(max)) if value > max => Err(format!("Must be at most {max}")),
+        _ => Ok(()),
+    })
+}
+
+/// The input is between `min` and `max` characters long, either bound may be left open.
+pub fn length<T: 'static>(min: Option<usize>, max: Option<usize>) -> Validator<T> {
+    Box::new(move |_, input| {
+        let len = input.chars().count();
+        match (min, max) {
+            (Some(min), _) if len < min => Err(format!("Must be at least {min} characters")),
+            (_, Some(max)) if len > max => Err(format!("Must be at most {max} characters")),
+            _ => Ok(()),
+        }
+    })
+}
+
+/// The input matches `pattern`.
+///
+/// # Panics
+/// If `pattern` is not a valid regex, the derive checks this at compile time.
+pub fn regex<T: 'static>(pattern: &str) -> Validator<T> {
+    let regex = Regex::new(pattern).expect("invalid regex");
+    Box::new(move |_, input| match regex.is_match(input) {
+        true => Ok(()),
+        false => Err(format!("Must match {}", regex.as_str())),
+    })
+}
+
+/// The input is not blank.
+pub fn non_empty<T: 'static>() -> Validator<T> {
+    Box::new(|_, input| match input.trim().is_empty() {
+        true => Err("Must not be empty".to_string()),
+        false => Ok(()),
+    })
+}
+
+/// The value equals one of `values`.
+pub fn one_of<T>(values: &[T]) -> Validator<T>
+where
+    T: PartialEq + Clone + Display + 'static,
+{
+    let values = values.to_vec();
+    Box::new(move |value, _| match values.contains(value) {
+        true => Ok(()),
+        false => {
+            let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+            Err(format!("Must be one of {}", values.join(", ")))
+        }
+    })
+}
+
+/// Checks the value with a user provided function.
+pub fn custom<T: 'static>(check: fn(&T) -> Result<(), String>) -> Validator<T> {
+    Box::new(move |value, _| check(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hide, length, one_of, range, FormErrors};
+
+    fn errors(paths: &[&str]) -> FormErrors {
+        paths.iter().map(|path| (*path, "error")).collect()
+    }
+
+    fn paths(errors: &[(String, String)]) -> Vec<&str> {
+        errors.iter().map(|(path, _)| path.as_str()).collect()
+    }
+
+    #[test]
+    fn outside_keeps_own_fields() {
+        let errors = errors(&["proxy.port", "proxy", "name", "hosts[0].name"]);
+        let outside = errors.outside(&["proxy", "hosts[0]"]);
+        assert_eq!(paths(&outside), ["proxy", "name"]);
+    }
+
+    #[test]
+    fn nested_strips_the_field() {
+        let errors = errors(&["proxy.port", "proxy", "proxy.tls.cert", "proxied.port"]);
+        assert_eq!(paths(&errors.nested("proxy").0), ["port", "tls.cert"]);
+        assert!(errors.nested("name").is_empty());
+    }
+
+    #[test]
+    fn hide_drops_nested_and_elements() {
+        let mut errors = errors(&["price", "price.tax", "prices", "tags[0]", "name"]).0;
+        hide(&mut errors, "price");
+        hide(&mut errors, "tags");
+        assert_eq!(paths(&errors), ["prices", "name"]);
+    }
+
+    #[test]
+    fn range_bounds() {
+        let validator = range(Some(1), Some(10));
+        assert!(validator(&1, "1").is_ok());
+        assert!(validator(&10, "10").is_ok());
+        assert_eq!(validator(&0, "0"), Err("Must be at least 1".to_string()));
+        assert_eq!(validator(&11, "11"), Err("Must be at most 10".to_string()));
+        assert!(range(None, Some(10))(&i32::MIN, "").is_ok());
+    }
+
+    #[test]
+    fn length_counts_characters() {
+        let validator = length::<String>(Some(2), Some(3));
+        assert!(validator(&String::new(), "äöü").is_ok());
+        assert!(validator(&String::new(), "a").is_err());
+        assert!(validator(&String::new(), "abcd").is_err());
+    }
+
+    #[test]
+    fn one_of_compares_values() {
+        let validator = one_of(&[-1, 1_000]);
+        assert!(validator(&-1, "-1").is_ok());
+        assert!(validator(&1000, "1000").is_ok());
+        assert_eq!(
+            validator(&1, "1"),
+            Err("Must be one of -1, 1000".to_string())
+        );
+        let validator = one_of::<String>(&["eu".into(), "us".into()]);
+        assert!(validator(&"eu".to_string(), "eu").is_ok());
+        assert!(validator(&"EU".to_string(), "EU").is_err());
+    }
+}
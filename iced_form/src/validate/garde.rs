@@ -0,0 +1,22 @@
+//! Runs the rules of the [`garde`](::garde) crate on built values.
+
+use ::garde::Validate;
+
+use super::PathErrors;
+
+/// The errors of [`Validate::validate`] keyed by the dotted path of each field.
+///
+/// Errors of nested structs are keyed as `field.nested` and errors of collections as `field[0]`.
+pub fn errors<T>(value: &T) -> PathErrors
+where
+    T: Validate,
+    T::Context: Default,
+{
+    match value.validate() {
+        Ok(()) => PathErrors::new(),
+        Err(report) => report
+            .iter()
+            .map(|(path, error)| (path.to_string(), error.message().to_string()))
+            .collect(),
+    }
+}
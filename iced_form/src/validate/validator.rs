@@ -0,0 +1,99 @@
+//! Runs the rules of the [`validator`](::validator) crate on built values.
+
+use ::validator::{Validate, ValidationError, ValidationErrors, ValidationErrorsKind};
+
+use super::PathErrors;
+use crate::nav;
+
+/// The errors of [`Validate::validate`] keyed by the dotted path of each field.
+///
+/// Errors of nested structs are keyed as `field.nested` and errors of collections as `field[0]`.
+pub fn errors<T>(value: &T) -> PathErrors
+where
+    T: Validate,
+{
+    match value.validate() {
+        Ok(()) => PathErrors::new(),
+        Err(errors) => flatten("", &errors),
+    }
+}
+
+fn flatten(path: &str, errors: &ValidationErrors) -> PathErrors {
+    errors
+        .errors()
+        .iter()
+        .flat_map(|(field, kind)| {
+            let path = nav::join(path, field);
+            match kind {
+                ValidationErrorsKind::Field(errors) => errors
+                    .iter()
+                    .map(|error| (path.clone(), message(error)))
+                    .collect(),
+                ValidationErrorsKind::Struct(errors) => flatten(&path, errors),
+                ValidationErrorsKind::List(errors) => errors
+                    .iter()
+                    .flat_map(|(idx, errors)| flatten(&format!("{path}[{idx}]"), errors))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+fn message(error: &ValidationError) -> String {
+    match &error.message {
+        Some(message) => message.to_string(),
+        None => format!("Failed {}", error.code),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::validator::Validate;
+
+    use super::errors;
+
+    #[derive(Validate)]
+    struct Address {
+        #[validate(range(min = 1000))]
+        zip: u32,
+    }
+
+    #[derive(Validate)]
+    struct Account {
+        #[validate(length(min = 3, message = "Too short"))]
+        name: String,
+        #[validate(nested)]
+        address: Address,
+        #[validate(nested)]
+        previous: Vec<Address>,
+    }
+
+    #[test]
+    fn flattens_nested_and_lists() {
+        let account = Account {
+            name: "me".to_string(),
+            address: Address { zip: 1 },
+            previous: vec![Address { zip: 12345 }, Address { zip: 2 }],
+        };
+        let mut errors = errors(&account);
+        errors.sort();
+        assert_eq!(
+            errors,
+            [
+                ("address.zip".to_string(), "Failed range".to_string()),
+                ("name".to_string(), "Too short".to_string()),
+                ("previous[1].zip".to_string(), "Failed range".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn valid_has_no_errors() {
+        let account = Account {
+            name: "someone".to_string(),
+            address: Address { zip: 12345 },
+            previous: Vec::new(),
+        };
+        assert!(errors(&account).is_empty());
+    }
+}
@@ -0,0 +1,588 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    meta::ParseNestedMeta, Attribute, Expr, ExprArray, ExprLit, ExprPath, Field, Ident, Lit,
+    LitInt, LitStr, Meta, MetaNameValue,
+};
+
+/// Mirrors `iced_form::layout::LabelPosition`
+#[derive(Clone, Copy, Default)]
+pub enum LabelPosition {
+    #[default]
+    Left,
+    Right,
+    Top,
+}
+
+impl LabelPosition {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "left" => Ok(Self::Left),
+            "right" => Ok(Self::Right),
+            "top" => Ok(Self::Top),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "expected one of \"left\", \"right\" or \"top\"",
+            )),
+        }
+    }
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Top => "top",
+        }
+    }
+    pub fn to_tokens(self) -> TokenStream {
+        match self {
+            Self::Left => quote! {::iced_form::layout::LabelPosition::Left},
+            Self::Right => quote! {::iced_form::layout::LabelPosition::Right},
+            Self::Top => quote! {::iced_form::layout::LabelPosition::Top},
+        }
+    }
+}
+
+/// Mirrors `iced_form::validate::ShowErrors`
+#[derive(Clone, Copy, Default)]
+#[allow(clippy::enum_variant_names)]
+pub enum ShowErrors {
+    OnChange,
+    #[default]
+    OnBlur,
+    OnSubmit,
+}
+
+impl ShowErrors {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "on_change" => Ok(Self::OnChange),
+            "on_blur" => Ok(Self::OnBlur),
+            "on_submit" => Ok(Self::OnSubmit),
+            _ => Err(syn::Error::new(
+                lit.span(),
+                "expected one of \"on_change\", \"on_blur\" or \"on_submit\"",
+            )),
+        }
+    }
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::OnChange => "on_change",
+            Self::OnBlur => "on_blur",
+            Self::OnSubmit => "on_submit",
+        }
+    }
+    pub fn to_tokens(self) -> TokenStream {
+        match self {
+            Self::OnChange => quote! {::iced_form::validate::ShowErrors::OnChange},
+            Self::OnBlur => quote! {::iced_form::validate::ShowErrors::OnBlur},
+            Self::OnSubmit => quote! {::iced_form::validate::ShowErrors::OnSubmit},
+        }
+    }
+}
+
+/// Mirrors `iced_form::choice::Picker`, from `#[form(widget = "radio", orientation = "vertical")]`
+#[derive(Clone, Copy, Default)]
+pub struct Picker {
+    widget: Option<Widget>,
+    orientation: Orientation,
+}
+
+#[derive(Clone, Copy)]
+enum Widget {
+    PickList,
+    Radio,
+    Segmented,
+    ComboBox,
+}
+
+#[derive(Clone, Copy, Default)]
+enum Orientation {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Picker {
+    /// Parses `widget = ..` or `orientation = ..`, returns whether `meta` was either
+    fn parse(&mut self, meta: &ParseNestedMeta) -> syn::Result<bool> {
+        if meta.path.is_ident("widget") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.widget =
+                Some(match lit.value().as_str() {
+                    "pick_list" => Widget::PickList,
+                    "radio" => Widget::Radio,
+                    "segmented" => Widget::Segmented,
+                    "combo_box" => Widget::ComboBox,
+                    _ => return Err(syn::Error::new(
+                        lit.span(),
+                        "expected one of \"pick_list\", \"radio\", \"segmented\" or \"combo_box\"",
+                    )),
+                });
+            Ok(true)
+        } else if meta.path.is_ident("orientation") {
+            let lit: LitStr = meta.value()?.parse()?;
+            self.orientation = match lit.value().as_str() {
+                "horizontal" => Orientation::Horizontal,
+                "vertical" => Orientation::Vertical,
+                _ => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "expected one of \"horizontal\" or \"vertical\"",
+                    ))
+                }
+            };
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+    pub fn is_set(&self) -> bool {
+        self.widget.is_some()
+    }
+    pub fn to_tokens(self) -> TokenStream {
+        let orientation = match self.orientation {
+            Orientation::Horizontal => quote! {::iced_form::choice::Orientation::Horizontal},
+            Orientation::Vertical => quote! {::iced_form::choice::Orientation::Vertical},
+        };
+        match self.widget {
+            None | Some(Widget::PickList) => quote! {::iced_form::choice::Picker::PickList},
+            Some(Widget::Radio) => quote! {::iced_form::choice::Picker::Radio(#orientation)},
+            Some(Widget::Segmented) => {
+                quote! {::iced_form::choice::Picker::Segmented(#orientation)}
+            }
+            Some(Widget::ComboBox) => quote! {::iced_form::choice::Picker::ComboBox},
+        }
+    }
+    /// The attribute to forward to the `XKind` enum picking the variant of a data enum
+    pub fn forwarded(self) -> TokenStream {
+        let widget = match self.widget {
+            None => return quote! {},
+            Some(Widget::PickList) => "pick_list",
+            Some(Widget::Radio) => "radio",
+            Some(Widget::Segmented) => "segmented",
+            Some(Widget::ComboBox) => "combo_box",
+        };
+        let orientation = match self.orientation {
+            Orientation::Horizontal => "horizontal",
+            Orientation::Vertical => "vertical",
+        };
+        quote! {#[form(widget = #widget, orientation = #orientation)]}
+    }
+}
+
+/// `#[form(...)]` attributes on the struct or enum deriving `FormBuilder`
+#[derive(Default)]
+pub struct FormAttrs {
+    pub label_position: LabelPosition,
+    pub breakpoints: Option<Vec<f32>>,
+    pub search: bool,
+    pub navigation: bool,
+    /// Cross-field validator from `#[form(validate = path::to::fn)]`
+    pub validate: Option<ExprPath>,
+    pub show_errors: ShowErrors,
+    /// Read-only rows from `#[form(computed(label = "Total", with = path::to::fn))]`
+    pub computed: Vec<Computed>,
+    /// The unit enum already implements `Display`, used to label its variants
+    pub use_display: bool,
+    /// The unit enum already defines the `ALL` array of its variants
+    pub no_all: bool,
+    /// The widget picking a variant of the enum
+    pub picker: Picker,
+    /// Fields with the same name and type move along when switching enum variants
+    pub carry_over: bool,
+}
+
+/// A read-only row showing a value derived from the builder of the form
+pub struct Computed {
+    pub label: LitStr,
+    /// A `fn(&XBuilder) -> impl Display`
+    pub with: ExprPath,
+}
+
+impl Computed {
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let mut label = None;
+        let mut with = None;
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("label") {
+                label = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("with") {
+                with = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `label` or `with`"))
+            }
+        })?;
+        match (label, with) {
+            (Some(label), Some(with)) => Ok(Self { label, with }),
+            _ => Err(meta.error("computed needs both `label` and `with`")),
+        }
+    }
+}
+
+impl FormAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+            attr.parse_nested_meta(|meta| {
+                if res.picker.parse(&meta)? {
+                    Ok(())
+                } else if meta.path.is_ident("label_position") {
+                    res.label_position = LabelPosition::from_lit(&meta.value()?.parse()?)?;
+                    Ok(())
+                } else if meta.path.is_ident("breakpoints") {
+                    let breakpoints: ExprArray = meta.value()?.parse()?;
+                    res.breakpoints = Some(
+                        breakpoints
+                            .elems
+                            .iter()
+                            .map(parse_width)
+                            .collect::<syn::Result<_>>()?,
+                    );
+                    Ok(())
+                } else if meta.path.is_ident("search") {
+                    res.search = true;
+                    Ok(())
+                } else if meta.path.is_ident("navigation") {
+                    res.navigation = true;
+                    Ok(())
+                } else if meta.path.is_ident("validate") {
+                    res.validate = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("show_errors") {
+                    res.show_errors = ShowErrors::from_lit(&meta.value()?.parse()?)?;
+                    Ok(())
+                } else if meta.path.is_ident("computed") {
+                    res.computed.push(Computed::parse(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("use_display") {
+                    res.use_display = true;
+                    Ok(())
+                } else if meta.path.is_ident("no_all") {
+                    res.no_all = true;
+                    Ok(())
+                } else if meta.path.is_ident("carry_over") {
+                    res.carry_over = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported form attribute"))
+                }
+            })?;
+        }
+        Ok(res)
+    }
+    pub fn breakpoints(&self) -> TokenStream {
+        match &self.breakpoints {
+            Some(breakpoints) => {
+                quote! {::iced_form::layout::Breakpoints::new(&[#(#breakpoints),*])}
+            }
+            None => quote! {::iced_form::layout::Breakpoints::default()},
+        }
+    }
+    /// The attributes to forward to the structs generated for enum variants
+    pub fn forwarded(&self) -> TokenStream {
+        let label_position = self.label_position.as_str();
+        let show_errors = self.show_errors.as_str();
+        quote! {#[form(label_position = #label_position, show_errors = #show_errors)]}
+    }
+}
+
+/// `#[form(...)]` attributes on an enum variant
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// Cross-field validator from `#[form(validate = path::to::fn)]`, given the built enum
+    pub validate: Option<ExprPath>,
+    /// Label of the variant from `#[form(rename = "2-wheeler")]`
+    pub rename: Option<LitStr>,
+    /// Left out of the picker, an existing value is still shown, read-only
+    pub skip: bool,
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("validate") {
+                    res.validate = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("rename") {
+                    res.rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    res.skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported form attribute"))
+                }
+            })?;
+        }
+        Ok(res)
+    }
+}
+
+fn parse_width(expr: &Expr) -> syn::Result<f32> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse(),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => lit.base10_parse(),
+        _ => Err(syn::Error::new_spanned(expr, "expected a width in pixels")),
+    }
+}
+
+/// `#[form(...)]` attributes on a field of the struct or enum variant
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub span: Option<usize>,
+    /// The doc comment of the field, searched along with its label
+    pub doc: String,
+    /// `iced_form::validate::Validator`s from `#[form(validate(...))]`
+    pub validators: Vec<TokenStream>,
+    /// Asynchronous validator from `#[form(validate_async = path::to::fn)]`
+    pub validate_async: Option<ExprPath>,
+    pub visible_if: Option<Condition>,
+    pub readonly: bool,
+    pub enabled_if: Option<Condition>,
+    /// Left out of the form and built from `default`, or the default of the struct
+    pub skip: bool,
+    pub default: Option<Expr>,
+    /// The widget picking a variant of an enum field, overriding the one of the enum
+    pub picker: Picker,
+    /// `#[form(label = "...")]`, an empty label shows the input or nested form in place
+    pub label: Option<LitStr>,
+    /// `#[form(none = "Inherit")]`, the label of `None` in the picker of an `Option<Enum>`
+    pub none: Option<LitStr>,
+    pub select: Option<Select>,
+    /// `#[form(flags)]` on a `bitflags!` type, `#[form(flags(hex))]` adds the raw hex input
+    pub flags: bool,
+    pub hex: bool,
+}
+
+/// `#[form(select(min = 1, max = 3, shortcuts))]` on a collection of a unit enum
+#[derive(Default)]
+pub struct Select {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+    /// "All" and "None" buttons
+    pub shortcuts: bool,
+}
+
+impl Select {
+    fn parse(meta: &ParseNestedMeta) -> syn::Result<Self> {
+        let mut res = Self::default();
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("min") {
+                res.min = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("max") {
+                res.max = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                Ok(())
+            } else if meta.path.is_ident("shortcuts") {
+                res.shortcuts = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `min`, `max` or `shortcuts`"))
+            }
+        })?;
+        match (res.min, res.max) {
+            (Some(min), Some(max)) if min > max => Err(meta.error("min is greater than max")),
+            _ => Ok(res),
+        }
+    }
+}
+
+/// `#[form(visible_if = ...)]` or `#[form(enabled_if = ...)]`, naming a bool field or giving a
+/// closure over the builder
+pub enum Condition {
+    Field(Ident),
+    Expr(Expr),
+}
+
+impl Condition {
+    fn parse(expr: Expr) -> syn::Result<Self> {
+        match expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => Ok(Self::Field(lit.parse()?)),
+            expr => Ok(Self::Expr(expr)),
+        }
+    }
+    /// Whether the condition holds for `self.builder` of type `builder_name`
+    pub fn tokens(&self, builder_name: &Ident) -> TokenStream {
+        match self {
+            Self::Field(field) => quote! {self.builder.#field.unwrap_or(false)},
+            Self::Expr(expr) => quote! {{
+                let condition: fn(&#builder_name) -> bool = #expr;
+                condition(&self.builder)
+            }},
+        }
+    }
+}
+
+impl FieldAttrs {
+    /// The label of field `f`, its name in title case unless given
+    pub fn label(&self, f: &Field) -> String {
+        match &self.label {
+            Some(label) => label.value(),
+            None => f.ident.as_ref().unwrap().to_string().to_case(Case::Title),
+        }
+    }
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut res = Self {
+            doc: doc(attrs),
+            ..Default::default()
+        };
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("form")) {
+            attr.parse_nested_meta(|meta| {
+                if res.picker.parse(&meta)? {
+                    Ok(())
+                } else if meta.path.is_ident("label") {
+                    res.label = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("flags") {
+                    res.flags = true;
+                    if meta.input.peek(syn::token::Paren) {
+                        meta.parse_nested_meta(|meta| match meta.path.is_ident("hex") {
+                            true => {
+                                res.hex = true;
+                                Ok(())
+                            }
+                            false => Err(meta.error("expected `hex`")),
+                        })?;
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("select") {
+                    res.select = Some(Select::parse(&meta)?);
+                    Ok(())
+                } else if meta.path.is_ident("none") {
+                    res.none = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("span") {
+                    res.span = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("visible_if") {
+                    res.visible_if = Some(Condition::parse(meta.value()?.parse()?)?);
+                    Ok(())
+                } else if meta.path.is_ident("readonly") {
+                    res.readonly = true;
+                    Ok(())
+                } else if meta.path.is_ident("enabled_if") {
+                    res.enabled_if = Some(Condition::parse(meta.value()?.parse()?)?);
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    res.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    res.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("validate_async") {
+                    res.validate_async = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("validate") {
+                    meta.parse_nested_meta(|meta| {
+                        res.validators.push(parse_validator(&meta)?);
+                        Ok(())
+                    })
+                } else {
+                    Err(meta.error("unsupported form attribute"))
+                }
+            })?;
+        }
+        match &res.default {
+            Some(default) if !res.skip => Err(syn::Error::new_spanned(
+                default,
+                "default is only supported along with skip",
+            )),
+            _ => Ok(res),
+        }
+    }
+}
+
+/// A single rule of `#[form(validate(...))]`
+fn parse_validator(meta: &ParseNestedMeta) -> syn::Result<TokenStream> {
+    if meta.path.is_ident("range") {
+        let (min, max) = parse_bounds(meta, |meta| meta.value()?.parse::<Expr>())?;
+        Ok(quote! {::iced_form::validate::range(#min, #max)})
+    } else if meta.path.is_ident("length") {
+        let (min, max) = parse_bounds(meta, |meta| meta.value()?.parse::<LitInt>())?;
+        Ok(quote! {::iced_form::validate::length(#min, #max)})
+    } else if meta.path.is_ident("regex") {
+        let pattern: LitStr = meta.value()?.parse()?;
+        if let Err(err) = regex::Regex::new(&pattern.value()) {
+            return Err(syn::Error::new(pattern.span(), err));
+        }
+        Ok(quote! {::iced_form::validate::regex(#pattern)})
+    } else if meta.path.is_ident("non_empty") {
+        Ok(quote! {::iced_form::validate::non_empty()})
+    } else if meta.path.is_ident("one_of") {
+        let values: ExprArray = meta.value()?.parse()?;
+        let values = values.elems.iter().map(|value| match value {
+            // Typed by the field, string literals are converted into it, e.g. into a `String`
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => quote! {::std::convert::Into::into(#lit)},
+            _ => quote! {#value},
+        });
+        Ok(quote! {::iced_form::validate::one_of(&[#(#values),*])})
+    } else if meta.path.is_ident("custom") {
+        let check: ExprPath = meta.value()?.parse()?;
+        Ok(quote! {::iced_form::validate::custom(#check)})
+    } else {
+        Err(meta.error("unsupported validator"))
+    }
+}
+
+/// The `min = .., max = ..` arguments of `range(...)` and `length(...)`, both optional
+fn parse_bounds<T: quote::ToTokens>(
+    meta: &ParseNestedMeta,
+    parse: impl Fn(&ParseNestedMeta) -> syn::Result<T>,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let mut min = quote! {None};
+    let mut max = quote! {None};
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("min") {
+            let value = parse(&meta)?;
+            min = quote! {Some(#value)};
+            Ok(())
+        } else if meta.path.is_ident("max") {
+            let value = parse(&meta)?;
+            max = quote! {Some(#value)};
+            Ok(())
+        } else {
+            Err(meta.error("expected `min` or `max`"))
+        }
+    })?;
+    Ok((min, max))
+}
+
+/// The `#[form(...)]` attributes and doc comments of a field, to be carried
+/// over to the structs generated for enum variants
+pub fn form_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("form") || attr.path().is_ident("doc"))
+}
+
+fn doc(attrs: &[Attribute]) -> String {
+    attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(MetaNameValue {
+                path,
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(doc), ..
+                    }),
+                ..
+            }) if path.is_ident("doc") => Some(doc.value().trim().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -1,16 +1,28 @@
-use crate::{form_message_name, form_name};
+use crate::{
+    attrs::{form_attrs, FieldAttrs, FormAttrs, VariantAttrs},
+    default_of, form_message_name, form_name, is_bool, is_form_field_type,
+};
+use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
-use quote::{format_ident, quote, quote_spanned};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
     spanned::Spanned, DataEnum, DeriveInput, Fields, FieldsNamed, FieldsUnnamed, Ident, Variant,
 };
 
-pub fn derive_for_enum(input: &DeriveInput, data: &DataEnum) -> TokenStream {
+pub fn derive_for_enum(input: &DeriveInput, data: &DataEnum, attrs: &FormAttrs) -> TokenStream {
+    if attrs.use_display || attrs.no_all {
+        return syn::Error::new(
+            input.ident.span(),
+            "use_display and no_all are only supported on enums without fields",
+        )
+        .to_compile_error()
+        .into();
+    }
     let form_enum = generate_form_enum(input, data);
-    let builders = generate_variant_builder(input, data);
+    let builders = generate_variant_builder(input, data, attrs);
     let messages = generate_form_message(input, data);
-    let kinds = generate_variant_kinds(input, data);
-    let wrapper = generate_form_wrapper(input, data);
+    let kinds = generate_variant_kinds(input, data, attrs);
+    let wrapper = generate_form_wrapper(input, data, attrs);
     let expanded = quote! {
         #builders
         #messages
@@ -74,6 +86,8 @@ fn generate_form_message(input: &DeriveInput, data: &DataEnum) -> proc_macro2::T
         #[derive(Debug,Clone)]
         #vis enum #form_message_name{
             #(#builder_variants ,)*
+            /// Submit was pressed while the form does not build
+            SubmitAttempted,
             #ident(#ident)
         }
     }
@@ -100,18 +114,150 @@ fn generate_form_enum(input: &DeriveInput, data: &DataEnum) -> proc_macro2::Toke
         let ident_str = format!("{}", ident);
         match &var.fields {
             Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
-                Self::#ident(form)=>form.view_nested().map(#msg_name::#ident)
+                Self::#ident(form)=>::iced_form::Form::view_nested(form).map(#msg_name::#ident)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => ::iced::widget::text(#ident_str).into()
+            },
+        }
+    });
+    let columns_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        let ident_str = format!("{}", ident);
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::view_columns(form, columns).map(#msg_name::#ident)
             },
             Fields::Unit => quote_spanned! {var.span()=>
                 Self::#ident => ::iced::widget::text(#ident_str).into()
             },
         }
     });
+    let filtered_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::view_filtered(form, query).map(|form| form.map(#msg_name::#ident))
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => None
+            },
+        }
+    });
+    let nav_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::nav_tree(form, label, path)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => None
+            },
+        }
+    });
+    let set_errors_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::set_errors(form, errors)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => {}
+            },
+        }
+    });
+    let submit_attempted_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::submit_attempted(form)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => {}
+            },
+        }
+    });
+    let init_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::init(form).map(#msg_name::#ident)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => ::iced::Command::none()
+            },
+        }
+    });
+    let first_invalid_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::first_invalid(form)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => None
+            },
+        }
+    });
+    let set_readonly_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::set_readonly(form, path, readonly)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => {}
+            },
+        }
+    });
+    let apply_errors_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::apply_errors(form, errors)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => {}
+            },
+        }
+    });
+    let is_edit_variants = data
+        .variants
+        .iter()
+        .filter(|var| !matches!(&var.fields, Fields::Unit))
+        .map(|var| {
+            let var_ident = &var.ident;
+            quote_spanned! {var.span()=>
+                (#msg_name::#var_ident(message), Self::#var_ident(form))=>::iced_form::Form::is_edit(form, message)
+            }
+        });
+    let is_pending_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::is_pending(form)
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => false
+            },
+        }
+    });
+    let view_at_variants = data.variants.iter().map(|var| {
+        let ident = &var.ident;
+        match &var.fields {
+            Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
+                Self::#ident(form)=>::iced_form::Form::view_at(form, path).map(|form| form.map(#msg_name::#ident))
+            },
+            Fields::Unit => quote_spanned! {var.span()=>
+                Self::#ident => None
+            },
+        }
+    });
     let build_variants = data.variants.iter().map(|var| {
         let var_ident = &var.ident;
         match &var.fields {
             Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
-                Self::#var_ident(form)=>form.build().map(|res|res.build())
+                Self::#var_ident(form)=>::iced_form::Form::build(form).map(|res|res.build())
             },
             Fields::Unit => quote_spanned! {var.span()=>
                 Self::#var_ident => Some(#ident::#var_ident)
@@ -126,24 +272,60 @@ fn generate_form_enum(input: &DeriveInput, data: &DataEnum) -> proc_macro2::Toke
             let var_ident = &var.ident;
             match &var.fields {
                 Fields::Named(_) | Fields::Unnamed(_) => quote_spanned! {var.span()=>
-                    (#msg_name::#var_ident(message), Self::#var_ident(form))=>form.update(message).map(#msg_name::#var_ident)
+                    (#msg_name::#var_ident(message), Self::#var_ident(form))=>::iced_form::Form::update(form, message).map(#msg_name::#var_ident)
                 },
                 Fields::Unit => unimplemented!(),
             }
         });
+    let from_value_variants = data.variants.iter().map(|var| {
+        let var_ident = &var.ident;
+        let builder_name = variant_builder_name(var);
+        match &var.fields {
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|f| &f.ident);
+                let names = quote! {#(#names),*};
+                quote_spanned! {var.span()=>
+                    #ident::#var_ident{#names} => Self::#var_ident(::iced_form::Form::from_value(#builder_name{#names}))
+                }
+            }
+            Fields::Unnamed(fields) => {
+                let names = (0..fields.unnamed.len()).map(|idx| format_ident!("field_{}", idx));
+                let names = quote! {#(#names),*};
+                quote_spanned! {var.span()=>
+                    #ident::#var_ident(#names) => Self::#var_ident(::iced_form::Form::from_value(#builder_name{#names}))
+                }
+            }
+            Fields::Unit => quote_spanned! {var.span()=>
+                #ident::#var_ident => Self::#var_ident
+            },
+        }
+    });
+    let skipped = data
+        .variants
+        .iter()
+        .filter(|var| !matches!(&var.fields, Fields::Unit))
+        .filter(|var| VariantAttrs::parse(&var.attrs).is_ok_and(|var_attrs| var_attrs.skip))
+        .map(|var| {
+            let var_ident = &var.ident;
+            quote_spanned! {var.span()=> Self::#var_ident(_)}
+        })
+        .collect::<Vec<_>>();
+    let forced_readonly = match skipped.is_empty() {
+        true => quote! {false},
+        false => quote! {matches!(self, #(#skipped)|*)},
+    };
     let form_name = form_enum_name(ident);
     let vis = &input.vis;
     quote! {
         #vis enum #form_name{
             #(#builder_variants ,)*
         }
+        #[allow(deprecated)]
         impl #form_name {
-        #vis fn view_nested<'a, Theme>(&'a self) -> ::iced::Element<'a, #msg_name, Theme>
-        where Theme: ::iced_form::Catalog + 'a
-        {
-            match self{
-                #(#view_variants,)*
-            }
+        /// Skipped variants can not be picked, a value of one is only shown, read-only whatever
+        /// `set_readonly` is given
+        fn forced_readonly(&self) -> bool {
+            #forced_readonly
         }
         #vis fn build(&self) -> ::std::option::Option<#ident>{
             match self {
@@ -151,28 +333,146 @@ fn generate_form_enum(input: &DeriveInput, data: &DataEnum) -> proc_macro2::Toke
             }
 
         }
+        /// The fields of the variant without a Submit button, to be shown within an enclosing form
+        #vis fn view_nested<'a, Theme>(&'a self) -> ::iced::Element<'a, #msg_name, Theme>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            match self{
+                #(#view_variants,)*
+            }
+        }
         #vis fn view<'a, Theme>(&'a self)-> ::iced::Element<'a, #msg_name, Theme>
         where Theme: ::iced_form::Catalog + 'a
         {
             ::iced::widget::column![
-                self.view_nested(),
-                ::iced::widget::button("Submit").on_press_maybe(self.build().map(#msg_name::#ident))
+                ::iced_form::Form::view_nested(self),
+                ::iced::widget::button("Submit")
+                    .on_press(self.build().map(#msg_name::#ident).unwrap_or(#msg_name::SubmitAttempted))
             ].into()
         }
         #vis fn update(&mut self, message:#msg_name)-> ::iced::Command<#msg_name>{
             match (message, self){
                 #(#update_variants ,)*
+                (#msg_name::SubmitAttempted, form) => {
+                    ::iced_form::Form::submit_attempted(form);
+                    ::iced::Command::none()
+                }
                 _ => { ::iced::Command::none() }
             }
         }
         }
+        #[allow(deprecated)]
+        impl ::iced_form::Form for #form_name {
+        type Value = #ident;
+        type Message = #msg_name;
+        fn from_value(value: #ident) -> Self {
+            let mut form = match value {
+                #(#from_value_variants,)*
+            };
+            if form.forced_readonly() {
+                ::iced_form::Form::set_readonly(&mut form, "", true);
+            }
+            form
+        }
+        fn init(&mut self) -> ::iced::Command<#msg_name> {
+            match self{
+                #(#init_variants,)*
+            }
+        }
+        fn build(&self) -> ::std::option::Option<#ident> {
+            #form_name::build(self)
+        }
+        fn update(&mut self, message: #msg_name) -> ::iced::Command<#msg_name> {
+            #form_name::update(self, message)
+        }
+        fn view_nested<'a, Theme>(&'a self) -> ::iced::Element<'a, #msg_name, Theme>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            #form_name::view_nested(self)
+        }
+        fn view_columns<'a, Theme>(&'a self, columns: usize) -> ::iced::Element<'a, #msg_name, Theme>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            match self{
+                #(#columns_variants,)*
+            }
+        }
+        fn view_filtered<'a, Theme>(&'a self, query: &str) -> ::std::option::Option<::iced::Element<'a, #msg_name, Theme>>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            match self{
+                #(#filtered_variants,)*
+            }
+        }
+        fn view_at<'a, Theme>(&'a self, path: &str) -> ::std::option::Option<::iced::Element<'a, #msg_name, Theme>>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            match self{
+                #(#view_at_variants,)*
+            }
+        }
+        fn nav_tree(&self, label: &str, path: &str) -> ::std::option::Option<::iced_form::nav::Node> {
+            match self{
+                #(#nav_variants,)*
+            }
+        }
+        fn set_errors(&mut self, errors: ::iced_form::validate::PathErrors) {
+            match self{
+                #(#set_errors_variants,)*
+            }
+        }
+        fn apply_errors(&mut self, errors: ::iced_form::validate::FormErrors) {
+            match self{
+                #(#apply_errors_variants,)*
+            }
+        }
+        fn is_edit(&self, message: &#msg_name) -> bool {
+            match (message, self){
+                #(#is_edit_variants,)*
+                _ => false,
+            }
+        }
+        fn is_pending(&self) -> bool {
+            match self{
+                #(#is_pending_variants,)*
+            }
+        }
+        fn submit_attempted(&mut self) {
+            match self{
+                #(#submit_attempted_variants,)*
+            }
+        }
+        fn set_readonly(&mut self, path: &str, readonly: bool) {
+            let readonly = readonly || (path.is_empty() && self.forced_readonly());
+            match self{
+                #(#set_readonly_variants,)*
+            }
+        }
+        fn first_invalid(&self) -> ::std::option::Option<::std::string::String> {
+            match self{
+                #(#first_invalid_variants,)*
+            }
+        }
+        }
     }
 }
-fn generate_variant_builder(input: &DeriveInput, data: &DataEnum) -> proc_macro2::TokenStream {
-    let builders = data.variants.iter().map(|var| match &var.fields {
-        Fields::Named(fields) => handle_named_variant(input, var, fields),
-        Fields::Unnamed(fields) => handle_unnamed_variant(input, var, fields),
-        Fields::Unit => quote! {},
+fn generate_variant_builder(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attrs: &FormAttrs,
+) -> proc_macro2::TokenStream {
+    let builders = data.variants.iter().map(|var| {
+        let var_attrs = match VariantAttrs::parse(&var.attrs) {
+            Ok(var_attrs) => var_attrs,
+            Err(err) => return err.to_compile_error(),
+        };
+        match &var.fields {
+            Fields::Named(fields) => handle_named_variant(input, var, fields, attrs, &var_attrs),
+            Fields::Unnamed(fields) => {
+                handle_unnamed_variant(input, var, fields, attrs, &var_attrs)
+            }
+            Fields::Unit => quote! {},
+        }
     });
     quote_spanned! {input.span()=>
         #(#builders)*
@@ -182,11 +482,14 @@ fn handle_named_variant(
     input: &DeriveInput,
     var: &Variant,
     fields: &FieldsNamed,
+    attrs: &FormAttrs,
+    var_attrs: &VariantAttrs,
 ) -> proc_macro2::TokenStream {
     let builder_fields = fields.named.iter().map(|f| {
         let field_name = &f.ident;
         let ty = &f.ty;
-        quote_spanned! {f.span()=> #field_name:#ty}
+        let attrs = form_attrs(&f.attrs);
+        quote_spanned! {f.span()=> #(#attrs)* #field_name:#ty}
     });
     let build_fields = fields.named.iter().map(|f| {
         let field_name = &f.ident;
@@ -196,12 +499,18 @@ fn handle_named_variant(
     let vis = &input.vis;
     let ident = &input.ident;
     let var_ident = &var.ident;
+    let form_attrs = attrs.forwarded();
+    let (validate_attr, validate) = variant_validate(var_attrs, &builder_name, ident, 0);
     quote! {
         #[derive(Clone,Debug,FormBuilder,::derive_builder::Builder, ::std::default::Default)]
+        #form_attrs
+        #validate_attr
         #vis struct #builder_name{
             #(#builder_fields,)*
         }
+        #[allow(deprecated)]
         impl #builder_name{
+            #validate
             #vis fn build(self)-> #ident{
                 #ident::#var_ident{#(#build_fields,)*}
             }
@@ -213,11 +522,19 @@ fn handle_unnamed_variant(
     input: &DeriveInput,
     var: &Variant,
     fields: &FieldsUnnamed,
+    attrs: &FormAttrs,
+    var_attrs: &VariantAttrs,
 ) -> proc_macro2::TokenStream {
+    // The field of a newtype variant is shown in place of the variant unless it is labeled
+    let newtype = fields.unnamed.len() == 1;
     let builder_fields = fields.unnamed.iter().enumerate().map(|(idx, f)| {
         let field_name = format_ident!("field_{}", idx);
         let ty = &f.ty;
-        quote_spanned! {f.span()=> #field_name:#ty}
+        let attrs = form_attrs(&f.attrs);
+        let label = (newtype
+            && FieldAttrs::parse(&f.attrs).is_ok_and(|attrs| attrs.label.is_none()))
+        .then(|| quote! {#[form(label = "")]});
+        quote_spanned! {f.span()=> #(#attrs)* #label #field_name:#ty}
     });
     let build_fields = fields.unnamed.iter().enumerate().map(|(idx, f)| {
         let field_name = format_ident!("field_{}", idx);
@@ -227,12 +544,19 @@ fn handle_unnamed_variant(
     let ident = &input.ident;
     let var_ident = &var.ident;
     let vis = &input.vis;
+    let form_attrs = attrs.forwarded();
+    let (validate_attr, validate) =
+        variant_validate(var_attrs, &builder_name, ident, fields.unnamed.len());
     quote! {
         #[derive(Clone,Debug,FormBuilder,::derive_builder::Builder, ::std::default::Default)]
+        #form_attrs
+        #validate_attr
         #vis struct #builder_name{
             #(#builder_fields,)*
         }
+        #[allow(deprecated)]
         impl #builder_name{
+            #validate
             #vis fn build(self)-> #ident{
                 #ident::#var_ident(#(#build_fields,)*)
             }
@@ -240,26 +564,104 @@ fn handle_unnamed_variant(
     }
 }
 
-fn generate_variant_kinds(input: &DeriveInput, data: &DataEnum) -> proc_macro2::TokenStream {
+/// Forwards the cross-field validator of a variant to its `Var` struct, the validator is given
+/// the enum built from the struct. Errors on the `unnamed` fields of a tuple variant are keyed by
+/// their index, `"0"`, `"1"`, .., and moved to the `field_0`, `field_1`, .. of the struct.
+fn variant_validate(
+    var_attrs: &VariantAttrs,
+    builder_name: &Ident,
+    ident: &Ident,
+    unnamed: usize,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let indices = (0..unnamed).map(|idx| idx.to_string());
+    let field_names = (0..unnamed).map(|idx| format!("field_{idx}"));
+    let rename = match unnamed {
+        0 => quote! {},
+        _ => quote! {
+            .map_err(|errors| {
+                errors
+                    .into_iter()
+                    .map(|(field, error)| match field {
+                        #(#indices => (#field_names, error),)*
+                        _ => (field, error),
+                    })
+                    .collect()
+            })
+        },
+    };
+    match &var_attrs.validate {
+        Some(validate) => (
+            quote! {#[form(validate = #builder_name::validate)]},
+            quote! {
+                fn validate(&self) -> ::std::result::Result<(), ::iced_form::validate::FieldErrors> {
+                    let val: #ident = self.clone().build();
+                    #validate(&val)#rename
+                }
+            },
+        ),
+        None => (quote! {}, quote! {}),
+    }
+}
+
+fn generate_variant_kinds(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attrs: &FormAttrs,
+) -> proc_macro2::TokenStream {
+    let picker = attrs.picker.forwarded();
     let ident = &input.ident;
     let vis = &input.vis;
     let kind_name = kind_name(ident);
     let kinds = data.variants.iter().map(|var| {
         let var_ident = &var.ident;
+        // Errors in the attributes are reported along with the variant builders
+        let var_attrs = VariantAttrs::parse(&var.attrs).unwrap_or_default();
+        let rename = var_attrs
+            .rename
+            .map(|rename| quote! {#[form(rename = #rename)]});
+        let skip = var_attrs.skip.then(|| quote! {#[form(skip)]});
+        let deprecated = var
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("deprecated"));
         quote_spanned! {var.span()=>
+            #rename
+            #skip
+            #(#deprecated)*
             #var_ident
         }
     });
+    let kinds_of = data.variants.iter().map(|var| {
+        let var_ident = &var.ident;
+        quote_spanned! {var.span()=>
+            #ident::#var_ident { .. } => Self::#var_ident
+        }
+    });
     quote_spanned! {input.span()=>
         #[derive(Clone, Debug, FormBuilder, PartialEq)]
+        #picker
         #vis enum #kind_name{
             #(#kinds, )*
         }
+        #[allow(deprecated)]
+        impl ::std::convert::From<&#ident> for #kind_name {
+            fn from(val: &#ident) -> Self {
+                match val {
+                    #(#kinds_of, )*
+                }
+            }
+        }
     }
 }
 
-fn generate_form_wrapper(input: &DeriveInput, data: &DataEnum) -> proc_macro2::TokenStream {
+fn generate_form_wrapper(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attrs: &FormAttrs,
+) -> proc_macro2::TokenStream {
     let ident = &input.ident;
+    let title_name = ident.to_string().to_case(Case::Title);
+    let label_position = attrs.label_position.to_tokens();
     let vis = &input.vis;
     let kind_name = kind_name(ident);
     let kind_message_name = form_message_name(&kind_name);
@@ -268,14 +670,31 @@ fn generate_form_wrapper(input: &DeriveInput, data: &DataEnum) -> proc_macro2::T
     let form_message_name = form_wrapper_message(ident);
     let form_enum_message = form_enum_message(ident);
     let wrapper_name = form_wrapper_name(ident);
+    let default = default_of(ident);
+    let breakpoints = attrs.breakpoints();
+    let carry_over = match attrs.carry_over {
+        true => generate_carry_over(data, &form_enum_name, &form_enum_message),
+        false => quote! {let command = ::iced::Command::none();},
+    };
 
     let kind_form_vars = data.variants.iter().map(|var| {
         let var_ident = &var.ident;
         match &var.fields {
             Fields::Named(_) | Fields::Unnamed(_) => {
                 let var_name = form_name(&variant_builder_name(var));
-                quote_spanned! {var.span()=>
-                    #kind_name::#var_ident=>#form_enum_name::#var_ident(#var_name::default())
+                // Skipped variants can not be picked, only an existing value is shown
+                let skip = VariantAttrs::parse(&var.attrs).is_ok_and(|var_attrs| var_attrs.skip);
+                match skip {
+                    true => quote_spanned! {var.span()=>
+                        #kind_name::#var_ident=>{
+                            let mut form = #form_enum_name::#var_ident(#var_name::default());
+                            ::iced_form::Form::set_readonly(&mut form, "", true);
+                            form
+                        }
+                    },
+                    false => quote_spanned! {var.span()=>
+                        #kind_name::#var_ident=>#form_enum_name::#var_ident(#var_name::default())
+                    },
                 }
             }
             Fields::Unit => {
@@ -291,53 +710,378 @@ fn generate_form_wrapper(input: &DeriveInput, data: &DataEnum) -> proc_macro2::T
         #vis enum #form_message_name{
             PickList(#kind_message_name),
             Form(#form_enum_message),
+            /// Submit was pressed while the form does not build
+            SubmitAttempted,
             #ident(#ident),
         }
-        #[derive(::std::default::Default)]
         #vis struct #wrapper_name{
             pick_list: #kind_form_name,
-            form: ::std::option::Option<#form_enum_name>
+            form: ::std::option::Option<#form_enum_name>,
+            /// Forms of the variants switched away from
+            cache: ::std::vec::Vec<(#kind_name, #form_enum_name)>,
+            readonly: bool,
+            /// Submit was pressed while a value was being checked, the form is submitted once
+            /// the checks are done
+            submit_queued: bool,
+            /// Id of the scrollable holding the fields, unique per form
+            scrollable_id: ::iced::widget::scrollable::Id,
         }
+        #[allow(deprecated)]
+        impl ::std::default::Default for #wrapper_name {
+            fn default() -> Self {
+                // The `Default` of the enum, if any, is preselected along with its field values
+                match #default {
+                    Some(val) => ::iced_form::Form::from_value(val),
+                    None => Self {
+                        pick_list: ::std::default::Default::default(),
+                        form: None,
+                        cache: ::std::vec::Vec::new(),
+                        readonly: false,
+                        submit_queued: false,
+                        scrollable_id: ::iced::widget::scrollable::Id::unique(),
+                    },
+                }
+            }
+        }
+        #[allow(deprecated)]
         impl #wrapper_name {
+            /// Switches to the form of the `kind` variant, the form of the variant switched from is
+            /// kept to be switched back to
+            fn select(&mut self, kind: #kind_name) -> ::iced::Command<#form_enum_message> {
+                let previous = ::iced_form::Form::build(&self.pick_list).zip(self.form.take());
+                if let Some((previous_kind, previous)) = previous {
+                    if previous_kind == kind {
+                        self.form = Some(previous);
+                        return ::iced::Command::none();
+                    }
+                    self.cache.push((previous_kind, previous));
+                }
+                // Fresh forms check their initial values, cached ones the values left unchecked
+                // while hidden
+                let mut form = match self.cache.iter().position(|(cached, _)| *cached == kind) {
+                    Some(idx) => self.cache.remove(idx).1,
+                    None => match &kind {
+                        #(#kind_form_vars,)*
+                    },
+                };
+                let init = ::iced_form::Form::init(&mut form);
+                #carry_over
+                self.form = Some(form);
+                let _ = ::iced_form::Form::update(&mut self.pick_list, #kind_message_name::#kind_name(kind));
+                ::iced::Command::batch([init, command])
+            }
             #vis fn build(&self)-> ::std::option::Option<#ident>{
                 self.form.as_ref().and_then(|val|val.build())
             }
+            /// Overrides the widget picking the variant, as given on the field of the enclosing form
+            #[doc(hidden)]
+            #vis fn set_picker(&mut self, picker: ::iced_form::choice::Picker) {
+                self.pick_list.set_picker(picker);
+            }
+            /// The picker and the fields of the variant picked without a title or Submit button, to
+            /// be shown within an enclosing form
             #vis fn view_nested<'a, Theme>(&'a self)-> ::iced::Element<'a, #form_message_name, Theme>
             where Theme: ::iced_form::Catalog + 'a
             {
                 let mut content = ::iced::widget::column![
-                    self.pick_list.view_nested().map(#form_message_name::PickList),
+                    ::iced_form::Form::view_nested(&self.pick_list).map(#form_message_name::PickList),
                 ];
                 if let Some(form) = &self.form{
-                    content = content.push(form.view_nested().map(#form_message_name::Form)).into()
+                    content = content.push(::iced_form::Form::view_nested(form).map(#form_message_name::Form))
                 }
                 content.into()
             }
             #vis fn view<'a, Theme>(&'a self) -> ::iced::Element<'a, #form_message_name, Theme>
             where Theme: ::iced_form::Catalog + 'a
             {
-                ::iced::widget::column![
-                    self.view_nested(),
-                    ::iced::widget::button("Submit").on_press_maybe(self.build().map(#form_message_name::#ident))
-                ].into()
+                let breakpoints = #breakpoints;
+                ::iced::widget::responsive(move |size| {
+                    let picker = ::iced_form::Form::view_nested(&self.pick_list).map(#form_message_name::PickList);
+                    let mut content = ::iced::widget::column![
+                        ::iced_form::layout::Grid::new(#label_position)
+                            .push(::iced::widget::text(#title_name), picker),
+                    ];
+                    if let Some(form) = &self.form {
+                        let columns = breakpoints.columns(size.width);
+                        content = content.push(::iced_form::Form::view_columns(form, columns).map(#form_message_name::Form));
+                    }
+                    let fields = ::iced::widget::scrollable(content)
+                        .id(self.scrollable_id.clone())
+                        .height(::iced::Length::Fill);
+                    let submit = ::iced::widget::button("Submit").on_press(
+                        self.build().map(#form_message_name::#ident).unwrap_or(#form_message_name::SubmitAttempted)
+                    );
+                    ::iced::widget::column![fields, submit].into()
+                }).into()
+            }
+            /// Scrolls to the field of the selected variant at the dotted `path`
+            #vis fn scroll_to(&mut self, path: &str) -> ::iced::Command<#form_message_name> {
+                ::iced_form::scroll::scroll_to(self.scrollable_id.clone(), path)
             }
             #vis fn update(&mut self, message: #form_message_name) -> ::iced::Command<#form_message_name> {
-                match message{
-                    #form_message_name::PickList(message)=>{
-                        if let #kind_message_name::#kind_name(kind) = &message {
-                            self.form = Some(match kind {
-                                #(#kind_form_vars,)*
-                            });
-                        }
-                        self.pick_list.update(message).map(#form_message_name::PickList)
+                if ::iced_form::Form::is_edit(self, &message) {
+                    self.submit_queued = false;
+                }
+                let cmd = match message{
+                    #form_message_name::PickList(_) if self.readonly => ::iced::Command::none(),
+                    #form_message_name::PickList(#kind_message_name::#kind_name(kind))=>{
+                        self.select(kind).map(#form_message_name::Form)
                     }
                     #form_message_name::Form(message)=> match self.form.as_mut() {
-                        Some(form)=> form.update(message).map(#form_message_name::Form),
+                        Some(form)=> ::iced_form::Form::update(form, message).map(#form_message_name::Form),
                         None => ::iced::Command::none(),
                     }
+                    #form_message_name::SubmitAttempted if ::iced_form::Form::is_pending(self) => {
+                        self.submit_queued = true;
+                        ::iced::Command::none()
+                    }
+                    #form_message_name::SubmitAttempted => {
+                        ::iced_form::Form::submit_attempted(self);
+                        match ::iced_form::Form::first_invalid(self) {
+                            Some(path) => ::iced::Command::batch([
+                                ::iced_form::scroll::scroll_to(self.scrollable_id.clone(), &path),
+                                ::iced_form::focus::focus(self.scrollable_id.clone(), &path),
+                            ]),
+                            None => ::iced::Command::none(),
+                        }
+                    }
                     _ => ::iced::Command::none(),
+                };
+                if self.submit_queued && !::iced_form::Form::is_pending(self) {
+                    self.submit_queued = false;
+                    let submit = match self.build() {
+                        Some(val) => ::iced::Command::perform(::std::future::ready(val), #form_message_name::#ident),
+                        None => self.update(#form_message_name::SubmitAttempted),
+                    };
+                    return ::iced::Command::batch([cmd, submit]);
+                }
+                cmd
+            }
+        }
+        #[allow(deprecated)]
+        impl ::iced_form::EnumForm for #wrapper_name {
+            type Kind = #kind_name;
+            fn kind(&self) -> ::std::option::Option<#kind_name> {
+                ::iced_form::Form::build(&self.pick_list)
+            }
+            fn pick(&mut self, kind: #kind_name) -> ::iced::Command<#form_message_name> {
+                self.select(kind).map(#form_message_name::Form)
+            }
+            fn picker(&self) -> ::iced_form::choice::Picker {
+                ::iced_form::EnumForm::picker(&self.pick_list)
+            }
+            fn view_fields<'a, Theme>(&'a self)-> ::std::option::Option<::iced::Element<'a, #form_message_name, Theme>>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                let form = self.form.as_ref()?;
+                Some(::iced_form::Form::view_nested(form).map(#form_message_name::Form))
+            }
+            fn view_fields_filtered<'a, Theme>(&'a self, query: &str)-> ::std::option::Option<::iced::Element<'a, #form_message_name, Theme>>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                ::iced_form::Form::view_filtered(self.form.as_ref()?, query)
+                    .map(|form| form.map(#form_message_name::Form))
+            }
+        }
+        #[allow(deprecated)]
+        impl ::iced_form::Form for #wrapper_name {
+            type Value = #ident;
+            type Message = #form_message_name;
+            fn from_value(value: #ident) -> Self {
+                Self {
+                    pick_list: ::iced_form::Form::from_value(#kind_name::from(&value)),
+                    form: Some(::iced_form::Form::from_value(value)),
+                    cache: ::std::vec::Vec::new(),
+                    readonly: false,
+                    submit_queued: false,
+                    scrollable_id: ::iced::widget::scrollable::Id::unique(),
+                }
+            }
+            fn init(&mut self) -> ::iced::Command<#form_message_name> {
+                match self.form.as_mut() {
+                    Some(form) => ::iced_form::Form::init(form).map(#form_message_name::Form),
+                    None => ::iced::Command::none(),
+                }
+            }
+            fn build(&self) -> ::std::option::Option<#ident> {
+                #wrapper_name::build(self)
+            }
+            fn update(&mut self, message: #form_message_name) -> ::iced::Command<#form_message_name> {
+                #wrapper_name::update(self, message)
+            }
+            fn view_nested<'a, Theme>(&'a self)-> ::iced::Element<'a, #form_message_name, Theme>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                #wrapper_name::view_nested(self)
+            }
+            fn view_filtered<'a, Theme>(&'a self, query: &str)-> ::std::option::Option<::iced::Element<'a, #form_message_name, Theme>>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                if query.trim().is_empty() {
+                    return Some(::iced_form::Form::view_nested(self));
+                }
+                let form = ::iced_form::Form::view_filtered(self.form.as_ref()?, query)?;
+                Some(::iced::widget::column![
+                    ::iced_form::Form::view_nested(&self.pick_list).map(#form_message_name::PickList),
+                    form.map(#form_message_name::Form),
+                ].into())
+            }
+            fn view_at<'a, Theme>(&'a self, path: &str)-> ::std::option::Option<::iced::Element<'a, #form_message_name, Theme>>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                if path.is_empty() {
+                    return Some(::iced_form::Form::view_nested(self));
+                }
+                ::iced_form::Form::view_at(self.form.as_ref()?, path).map(|form| form.map(#form_message_name::Form))
+            }
+            fn nav_tree(&self, label: &str, path: &str) -> ::std::option::Option<::iced_form::nav::Node> {
+                // The variant form stands in for the enum itself, only its nested forms are kept
+                let children = self
+                    .form
+                    .as_ref()
+                    .and_then(|form| ::iced_form::Form::nav_tree(form, label, path))
+                    .map(|node| node.children)
+                    .unwrap_or_default();
+                Some(::iced_form::nav::Node::new(label, path, self.build().is_some(), children))
+            }
+            fn set_errors(&mut self, errors: ::iced_form::validate::PathErrors) {
+                if let Some(form) = self.form.as_mut() {
+                    ::iced_form::Form::set_errors(form, errors);
+                }
+            }
+            fn is_edit(&self, message: &#form_message_name) -> bool {
+                match (message, &self.form) {
+                    (#form_message_name::PickList(_), _) => true,
+                    (#form_message_name::Form(message), Some(form)) => ::iced_form::Form::is_edit(form, message),
+                    _ => false,
                 }
             }
+            fn is_pending(&self) -> bool {
+                self.form.as_ref().is_some_and(::iced_form::Form::is_pending)
+            }
+            fn submit_attempted(&mut self) {
+                if let Some(form) = self.form.as_mut() {
+                    ::iced_form::Form::submit_attempted(form);
+                }
+            }
+            /// The dotted path of the first field of the selected variant that does not build or
+            /// has an error, empty when no variant is selected
+            fn first_invalid(&self) -> ::std::option::Option<::std::string::String> {
+                match &self.form {
+                    Some(form) => ::iced_form::Form::first_invalid(form),
+                    None => Some(::std::string::String::new()),
+                }
+            }
+            /// Makes the field of the selected variant at the dotted `path` read-only or editable
+            /// again, an empty path also keeps the variant from changing
+            fn set_readonly(&mut self, path: &str, readonly: bool) {
+                if path.is_empty() {
+                    self.readonly = readonly;
+                    ::iced_form::Form::set_readonly(&mut self.pick_list, path, readonly);
+                }
+                if let Some(form) = self.form.as_mut() {
+                    ::iced_form::Form::set_readonly(form, path, readonly);
+                }
+            }
+            /// Shows `errors` computed outside the form below the fields of the selected variant,
+            /// keyed by their dotted paths
+            fn apply_errors(&mut self, errors: ::iced_form::validate::FormErrors) {
+                if let Some(form) = self.form.as_mut() {
+                    ::iced_form::Form::apply_errors(form, errors);
+                }
+            }
+        }
+    }
+}
+
+/// Swaps the fields with the same name and type between the form switched from, the last one
+/// cached, and the `form` switched to, so they keep their input across variants. Inputs are moved
+/// between the fields rather than the fields themselves, so each variant keeps its own validators
+/// and the input is checked against those of the variant switched to
+fn generate_carry_over(
+    data: &DataEnum,
+    form_enum_name: &Ident,
+    form_enum_message: &Ident,
+) -> proc_macro2::TokenStream {
+    let named: Vec<_> = data
+        .variants
+        .iter()
+        .filter_map(|var| match &var.fields {
+            Fields::Named(fields) => Some((var, fields)),
+            _ => None,
+        })
+        .collect();
+    let pairs = named.iter().flat_map(|(from, from_fields)| {
+        named
+            .iter()
+            .filter(move |(to, _)| to.ident != from.ident)
+            .filter_map(move |(to, to_fields)| {
+                let to_message = form_message_name(&variant_builder_name(to));
+                let to_ident = &to.ident;
+                let swaps: Vec<_> = from_fields
+                    .named
+                    .iter()
+                    .filter(|f| {
+                        to_fields.named.iter().any(|g| {
+                            g.ident == f.ident
+                                && g.ty.to_token_stream().to_string()
+                                    == f.ty.to_token_stream().to_string()
+                        })
+                    })
+                    .map(|f| {
+                        let name = &f.ident;
+                        let pascal_name = format_ident!(
+                            "{}",
+                            name.clone().unwrap().to_string().to_case(Case::Pascal)
+                        );
+                        let skip = FieldAttrs::parse(&f.attrs).is_ok_and(|attrs| attrs.skip);
+                        if is_bool(&f.ty) || skip {
+                            // Bools and skipped fields only live in the builder
+                            quote_spanned! {f.span()=>
+                                ::std::mem::swap(&mut from.builder.#name, &mut to.builder.#name);
+                            }
+                        } else if is_form_field_type(&f.ty) {
+                            quote_spanned! {f.span()=>
+                                let input = from.#name.take_input();
+                                let replaced = to.#name.take_input();
+                                commands.push(
+                                    to.#name
+                                        .set_input(input)
+                                        .map(#to_message::#pascal_name)
+                                        .map(#form_enum_message::#to_ident),
+                                );
+                                // The form switched from is hidden, the check of its input is
+                                // started again by `Form::init` when it is switched back to
+                                let _ = from.#name.set_input(replaced);
+                                to.builder.#name = to.#name.value().cloned();
+                                from.builder.#name = from.#name.value().cloned();
+                            }
+                        } else {
+                            quote_spanned! {f.span()=>
+                                ::std::mem::swap(&mut from.#name, &mut to.#name);
+                                ::std::mem::swap(&mut from.builder.#name, &mut to.builder.#name);
+                            }
+                        }
+                    })
+                    .collect();
+                let from_ident = &from.ident;
+                (!swaps.is_empty()).then(|| {
+                    quote! {
+                        (#form_enum_name::#from_ident(from), #form_enum_name::#to_ident(to)) => {
+                            #(#swaps)*
+                        }
+                    }
+                })
+            })
+    });
+    quote! {
+        let mut commands = ::std::vec::Vec::new();
+        if let Some((_, previous)) = self.cache.last_mut() {
+            match (previous, &mut form) {
+                #(#pairs)*
+                _ => {}
+            }
         }
+        let command = ::iced::Command::batch(commands);
     }
 }
@@ -1,71 +1,875 @@
-use crate::{form_message_name, form_name, get_type_ident, is_bool, is_form_field_type};
+use crate::{
+    attrs::{FieldAttrs, FormAttrs, ShowErrors},
+    form_message_name, form_message_type, form_name, form_type, is_bool, is_form_field_type,
+    option_inner, set_inner,
+};
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
-use syn::{spanned::Spanned, DataStruct, DeriveInput, Fields};
+use syn::{spanned::Spanned, DataStruct, DeriveInput, Field, Fields};
 
-pub fn derive_for_struct(input: &DeriveInput, data: &DataStruct) -> TokenStream {
+pub fn derive_for_struct(input: &DeriveInput, data: &DataStruct, attrs: &FormAttrs) -> TokenStream {
     let vis = &input.vis;
     let name = &input.ident;
     let form_name = form_name(name);
     let form_message = form_message_name(name);
     let builder_name = format_ident!("{}Builder", name);
-    let (enum_variants, form_fields, form_default, form_view, form_update) =
-        gen_fields(&data.fields, &form_message);
+    let label_position = attrs.label_position.to_tokens();
+    let breakpoints = attrs.breakpoints();
+    let (search_variant, search_field, search_default, search_input, search_update) =
+        if attrs.search {
+            (
+                quote! {SearchQuery(::std::string::String),},
+                quote! {search_query: ::std::string::String,},
+                quote! {search_query: ::std::default::Default::default(),},
+                quote! {
+                    ::iced::widget::text_input("Search", &self.search_query)
+                        .on_input(#form_message::SearchQuery),
+                },
+                quote! {
+                    #form_message::SearchQuery(query) => {
+                        self.search_query = query;
+                        ::iced::Command::none()
+                    }
+                },
+            )
+        } else {
+            Default::default()
+        };
+    let query = match attrs.search {
+        true => quote! {self.search_query.as_str()},
+        false => quote! {""},
+    };
+    let checks = validation_checks(input, data, attrs);
+    let show_errors = attrs.show_errors.to_tokens();
+    let title_name = name.to_string().to_case(Case::Title);
+    let (nav_variant, nav_field, nav_default, nav_content, nav_breadcrumbs, nav_update, nav_reveal) =
+        if attrs.navigation {
+            (
+                quote! {Navigate(::std::string::String),},
+                quote! {nav_selected: ::std::string::String,},
+                quote! {nav_selected: ::std::default::Default::default(),},
+                quote! {
+                    let tree = self.nav_node(#title_name, "");
+                    let content = match ::iced_form::Form::view_at(self, &self.nav_selected) {
+                        Some(content) if !self.nav_selected.is_empty() => content,
+                        _ => grid.into(),
+                    };
+                },
+                quote! {
+                    ::iced_form::nav::breadcrumbs(&tree, &self.nav_selected, #form_message::Navigate),
+                },
+                quote! {
+                    #form_message::Navigate(path) => {
+                        self.nav_selected = path;
+                        ::iced::Command::none()
+                    }
+                },
+                // Fields outside the sub-form shown are revealed by going back to the whole form
+                quote! {
+                    match ::iced_form::nav::relative(path, &self.nav_selected) {
+                        Some(rest) => rest,
+                        None => {
+                            self.nav_selected.clear();
+                            path
+                        }
+                    }
+                },
+            )
+        } else {
+            (
+                quote! {},
+                quote! {},
+                quote! {},
+                quote! {let content = grid;},
+                quote! {},
+                quote! {},
+                quote! {path},
+            )
+        };
+    let body = match attrs.navigation {
+        true => quote! {
+            ::iced::widget::row![
+                ::iced::widget::scrollable(
+                    ::iced_form::nav::sidebar(&tree, &self.nav_selected, #form_message::Navigate)
+                )
+                .width(::iced::Length::Fixed(220.0)),
+                body,
+            ]
+            .spacing(10)
+            .into()
+        },
+        false => quote! {body.into()},
+    };
+    let field_attrs = match data
+        .fields
+        .iter()
+        .map(|f| FieldAttrs::parse(&f.attrs))
+        .collect::<syn::Result<Vec<_>>>()
+    {
+        Ok(field_attrs) => field_attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    if let Some((f, _)) = data.fields.iter().zip(&field_attrs).find(|(f, attrs)| {
+        (!attrs.validators.is_empty() || attrs.validate_async.is_some())
+            && !is_form_field_type(&f.ty)
+    }) {
+        return syn::Error::new(
+            f.span(),
+            "validate is only supported on fields edited with a FormField",
+        )
+        .to_compile_error()
+        .into();
+    }
+    if let Some((f, _)) = data.fields.iter().zip(&field_attrs).find(|(f, attrs)| {
+        attrs.picker.is_set()
+            && (is_bool(&f.ty) || is_form_field_type(&f.ty) || set_inner(&f.ty).is_some())
+    }) {
+        return syn::Error::new(f.span(), "widget is only supported on enum fields")
+            .to_compile_error()
+            .into();
+    }
+    if let Some((f, _)) = data
+        .fields
+        .iter()
+        .zip(&field_attrs)
+        .find(|(f, attrs)| attrs.none.is_some() && option_inner(&f.ty).is_none())
+    {
+        return syn::Error::new(f.span(), "none is only supported on Option fields")
+            .to_compile_error()
+            .into();
+    }
+    if let Some((f, _)) = data
+        .fields
+        .iter()
+        .zip(&field_attrs)
+        .find(|(f, attrs)| attrs.select.is_some() && set_inner(&f.ty).is_none())
+    {
+        return syn::Error::new(
+            f.span(),
+            "select is only supported on HashSet, BTreeSet and Vec fields",
+        )
+        .to_compile_error()
+        .into();
+    }
+    if let Some((f, _)) = data
+        .fields
+        .iter()
+        .zip(&field_attrs)
+        .find(|(_, attrs)| attrs.flags && !cfg!(feature = "bitflags"))
+    {
+        return syn::Error::new(
+            f.span(),
+            "flags needs the `bitflags` feature of iced_form_derive",
+        )
+        .to_compile_error()
+        .into();
+    }
+    if let Some((f, _)) = data.fields.iter().zip(&field_attrs).find(|(f, attrs)| {
+        attrs.flags && (is_bool(&f.ty) || is_form_field_type(&f.ty) || attrs.picker.is_set())
+    }) {
+        return syn::Error::new(f.span(), "flags is only supported on bitflags types")
+            .to_compile_error()
+            .into();
+    }
+    let Fields::Named(named) = &data.fields else {
+        unimplemented!()
+    };
+    // Skipped fields are only set on the builder, with their default value
+    let (skipped, shown): (Vec<_>, Vec<_>) = named
+        .named
+        .iter()
+        .zip(field_attrs)
+        .partition(|(_, attrs)| attrs.skip);
+    let (fields, field_attrs): (Vec<&Field>, Vec<FieldAttrs>) = shown.into_iter().unzip();
+    let skip_defaults = skipped.iter().map(|(f, _)| {
+        let name = &f.ident;
+        quote_spanned! {f.span()=> form.builder.#name = Some(def.#name);}
+    });
+    let skip_defaults = quote! {#(#skip_defaults)*};
+    // A `default` given on a skipped field only applies to fresh forms, loaded values keep theirs
+    let skip_exprs = skipped.iter().filter_map(|(f, attrs)| {
+        let name = &f.ident;
+        let default = attrs.default.as_ref()?;
+        Some(quote_spanned! {f.span()=> def.#name = #default;})
+    });
+    let skip_exprs: Vec<_> = skip_exprs.collect();
+    let skip_exprs = match skip_exprs.is_empty() {
+        true => quote! {let def = #name::default();},
+        false => quote! {let mut def = #name::default(); #(#skip_exprs)*},
+    };
+    let bool_defaults = fields.iter().filter(|f| is_bool(&f.ty)).map(|f| {
+        let name = &f.ident;
+        quote_spanned! {f.span()=> form.builder.#name = Some(def.#name);}
+    });
+    let bool_defaults = quote! {#(#bool_defaults)*};
+    let pickers = fields
+        .iter()
+        .zip(&field_attrs)
+        .filter(|(_, attrs)| attrs.picker.is_set())
+        .map(|(f, attrs)| {
+            let name = &f.ident;
+            let picker = attrs.picker.to_tokens();
+            quote_spanned! {f.span()=> form.#name.set_picker(#picker);}
+        });
+    let none_labels = fields
+        .iter()
+        .zip(&field_attrs)
+        .filter_map(|(f, attrs)| Some((f, attrs.none.as_ref()?)))
+        .map(|(f, none)| {
+            let name = &f.ident;
+            quote_spanned! {f.span()=> form.#name.set_none_label(#none);}
+        });
+    let selects = fields
+        .iter()
+        .zip(&field_attrs)
+        .filter_map(|(f, attrs)| Some((f, attrs.select.as_ref()?)))
+        .map(|(f, select)| {
+            let name = &f.ident;
+            let min = quote_option(select.min);
+            let max = quote_option(select.max);
+            let shortcuts = select.shortcuts;
+            quote_spanned! {f.span()=>
+                form.#name.set_bounds(#min, #max);
+                form.#name.set_shortcuts(#shortcuts);
+            }
+        });
+    let hex = fields
+        .iter()
+        .zip(&field_attrs)
+        .filter(|(_, attrs)| attrs.hex)
+        .map(|(f, _)| {
+            let name = &f.ident;
+            quote_spanned! {f.span()=> form.#name.set_hex(true);}
+        });
+    let pickers = quote! {#(#pickers)* #(#none_labels)* #(#selects)* #(#hex)*};
+    let visible: Vec<_> = field_attrs
+        .iter()
+        .map(|attrs| {
+            attrs
+                .visible_if
+                .as_ref()
+                .map(|condition| condition.tokens(&builder_name))
+        })
+        .collect();
+    let editable: Vec<_> = fields
+        .iter()
+        .zip(&field_attrs)
+        .map(|(f, attrs)| {
+            let id = f.ident.clone().unwrap().to_string();
+            let enabled = match (attrs.readonly, &attrs.enabled_if) {
+                (true, _) => quote! {&& false},
+                (false, Some(condition)) => {
+                    let condition = condition.tokens(&builder_name);
+                    quote! {&& #condition}
+                }
+                (false, None) => quote! {},
+            };
+            quote! {(!self.readonly && !self.readonly_fields.contains(#id) #enabled)}
+        })
+        .collect();
+    // Hidden fields are built with their default value, the others with the value shown, so a
+    // fresh form builds from the values it starts with
+    let shown_values = fields.iter().zip(&visible).map(|(f, visible)| {
+        let name = &f.ident;
+        let shown = if is_bool(&f.ty) {
+            quote! {Some(builder.#name.unwrap_or(false))}
+        } else if is_form_field_type(&f.ty) {
+            quote! {self.#name.value().cloned()}
+        } else {
+            quote! {::iced_form::Form::build(&self.#name)}
+        };
+        match visible {
+            Some(visible) => quote_spanned! {f.span()=>
+                builder.#name = if #visible { #shown } else { Some(def.#name) };
+            },
+            None => quote_spanned! {f.span()=> builder.#name = #shown;},
+        }
+    });
+    let hide_errors: Vec<_> = fields
+        .iter()
+        .zip(&visible)
+        .filter_map(|(f, visible)| {
+            let id = f.ident.clone().unwrap().to_string();
+            visible.as_ref().map(|visible| {
+                quote_spanned! {f.span()=>
+                    if !#visible {
+                        ::iced_form::validate::hide(&mut errors, #id);
+                    }
+                }
+            })
+        })
+        .collect();
+    let hide_errors = match hide_errors.is_empty() {
+        true => quote! {},
+        false => quote! {let mut errors = errors; #(#hide_errors)*},
+    };
+    let def = match visible.iter().any(Option::is_some) {
+        true => quote! {let def = #name::default();},
+        false => quote! {},
+    };
+    let shown_builder = quote! {
+        let mut builder = self.builder.clone();
+        #def
+        #(#shown_values)*
+        builder
+    };
+    let (enum_variants, form_fields, form_default, form_view, form_update) = gen_fields(
+        &fields,
+        &field_attrs,
+        &visible,
+        &editable,
+        &form_message,
+        &show_errors,
+    );
+    let (nav_children, view_at) = gen_navigation(&fields, &field_attrs, &form_message);
+    let nested = fields
+        .iter()
+        .filter(|f| !is_bool(&f.ty) && !is_form_field_type(&f.ty));
+    let nested_ids: Vec<_> = nested
+        .clone()
+        .map(|f| f.ident.clone().unwrap().to_string())
+        .collect();
+    let push_errors = nested.clone().map(|f| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        quote_spanned! {f.span()=>
+            ::iced_form::Form::set_errors(&mut self.#name, ::iced_form::validate::nested(
+                self.field_errors.iter().chain(&self.parent_errors),
+                #id,
+            ));
+        }
+    });
+    let apply_errors = nested.map(|f| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        quote_spanned! {f.span()=>
+            ::iced_form::Form::apply_errors(&mut self.#name, errors.nested(#id));
+        }
+    });
+    let apply_errors = quote! {#(#apply_errors)*};
+    let edited_fields = fields.iter().map(|f| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        let pascal_name = format_ident!("{}", id.to_case(Case::Pascal));
+        if is_bool(&f.ty) {
+            quote_spanned! {f.span()=>
+                #form_message::#pascal_name(_) => Some(#id),
+            }
+        } else if is_form_field_type(&f.ty) {
+            quote_spanned! {f.span()=>
+                #form_message::#pascal_name(message) if message.is_edit() => Some(#id),
+            }
+        } else {
+            quote_spanned! {f.span()=>
+                #form_message::#pascal_name(message) if ::iced_form::Form::is_edit(&self.#name, message) => Some(#id),
+            }
+        }
+    });
+    let edited_fields = quote! {#(#edited_fields)*};
+    let is_pending = fields.iter().filter(|f| !is_bool(&f.ty)).map(|f| {
+        let name = &f.ident;
+        match is_form_field_type(&f.ty) {
+            true => quote_spanned! {f.span()=> self.#name.is_pending()},
+            false => quote_spanned! {f.span()=> ::iced_form::Form::is_pending(&self.#name)},
+        }
+    });
+    let is_pending: Vec<_> = is_pending.collect();
+    let is_pending = match is_pending.is_empty() {
+        true => quote! {false},
+        false => quote! {#(#is_pending)||*},
+    };
+    let submit_attempted = fields.iter().filter(|f| !is_bool(&f.ty)).map(|f| {
+        let name = &f.ident;
+        match is_form_field_type(&f.ty) {
+            true => quote_spanned! {f.span()=> self.#name.submit_attempted();},
+            false => {
+                quote_spanned! {f.span()=> ::iced_form::Form::submit_attempted(&mut self.#name);}
+            }
+        }
+    });
+    let submit_attempted = quote! {#(#submit_attempted)*};
+    let init = fields.iter().filter(|f| !is_bool(&f.ty)).map(|f| {
+        let name = &f.ident;
+        let pascal_name = format_ident!(
+            "{}",
+            name.clone().unwrap().to_string().to_case(Case::Pascal)
+        );
+        match is_form_field_type(&f.ty) {
+            true => quote_spanned! {f.span()=>
+                self.#name.check_initial().map(#form_message::#pascal_name)
+            },
+            false => quote_spanned! {f.span()=>
+                ::iced_form::Form::init(&mut self.#name).map(#form_message::#pascal_name)
+            },
+        }
+    });
+    let init = quote! {::iced::Command::batch([#(#init),*])};
+    let first_invalid = fields.iter().zip(&visible).map(|(f, visible)| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        let invalid = if is_bool(&f.ty) {
+            quote! {self.field_error(#id).map(|_| #id.to_string())}
+        } else if is_form_field_type(&f.ty) {
+            quote! {
+                (self.field_error(#id).is_some() || self.#name.value().is_none())
+                    .then(|| #id.to_string())
+            }
+        } else {
+            quote! {
+                self.field_error(#id).map(|_| #id.to_string()).or_else(|| {
+                    ::iced_form::Form::first_invalid(&self.#name)
+                        .map(|path| ::iced_form::nav::join(#id, &path))
+                })
+            }
+        };
+        let invalid = match visible {
+            Some(visible) => quote! {#visible.then(|| #invalid).flatten()},
+            None => invalid,
+        };
+        quote_spanned! {f.span()=>
+            if let Some(path) = #invalid {
+                return Some(path);
+            }
+        }
+    });
+    let first_invalid = quote! {#(#first_invalid)*};
+    // Errors of validators are only shown per the `show_errors` policy, applied ones always
+    let (errors_visible, blurred) = match attrs.show_errors {
+        ShowErrors::OnChange => (quote! {true}, quote! {}),
+        ShowErrors::OnBlur => {
+            let blurred = fields
+                .iter()
+                .filter(|f| is_form_field_type(&f.ty))
+                .map(|f| {
+                    let name = &f.ident;
+                    let id = name.clone().unwrap().to_string();
+                    quote_spanned! {f.span()=> #id => self.#name.is_blurred(),}
+                });
+            (
+                quote! {self.submitted || self.blurred(field)},
+                quote! {
+                    fn blurred(&self, field: &str) -> bool {
+                        match field {
+                            #(#blurred)*
+                            _ => true,
+                        }
+                    }
+                },
+            )
+        }
+        ShowErrors::OnSubmit => (quote! {self.submitted}, quote! {}),
+    };
+    let push_errors = quote! {#(#push_errors)*};
+    let computed = attrs.computed.iter().map(|computed| {
+        let label = &computed.label;
+        let with = &computed.with;
+        quote_spanned! {with.span()=>
+            let grid = if ::iced_form::search::matches(query, &[#label]) {
+                let value = #with(&self.shown_builder());
+                grid.push_row(::iced_form::layout::Row::new(
+                    ::iced_form::search::label(#label, query),
+                    ::iced::widget::text(value.to_string()),
+                ))
+            } else {
+                grid
+            };
+        }
+    });
+    let computed = quote! {#(#computed)*};
+    let nested_editable = fields
+        .iter()
+        .zip(&editable)
+        .filter(|(f, _)| !is_bool(&f.ty) && !is_form_field_type(&f.ty));
+    let set_readonly_nested = nested_editable.clone().map(|(f, _)| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        quote_spanned! {f.span()=>
+            (#id, rest) if !rest.is_empty() => ::iced_form::Form::set_readonly(&mut self.#name, rest, readonly),
+        }
+    });
+    let set_readonly_nested = quote! {#(#set_readonly_nested)*};
+    let sync_readonly = nested_editable.map(|(f, editable)| {
+        let name = &f.ident;
+        quote_spanned! {f.span()=>
+            let readonly = !#editable;
+            ::iced_form::Form::set_readonly(&mut self.#name, "", readonly);
+        }
+    });
+    let sync_readonly = quote! {#(#sync_readonly)*};
     let expanded = quote! {
         #[derive(Debug,Clone)]
         #vis enum #form_message{
             #enum_variants
+            #search_variant
+            #nav_variant
+            /// Submit was pressed while the form does not build
+            SubmitAttempted,
             #name(#name),
         }
         #vis struct #form_name{
             builder: #builder_name,
+            /// Errors of the validators of the struct, by path
+            field_errors: ::iced_form::validate::PathErrors,
+            /// Errors of the validators of the enclosing forms
+            parent_errors: ::iced_form::validate::PathErrors,
+            /// Errors from `apply_errors`, kept until the field is edited
+            applied_errors: ::iced_form::validate::PathErrors,
+            submitted: bool,
+            /// Submit was pressed while a value was being checked, the form is submitted once
+            /// the checks are done
+            submit_queued: bool,
+            /// The whole form is read-only
+            readonly: bool,
+            /// Fields made read-only through `set_readonly`
+            readonly_fields: ::std::collections::BTreeSet<::std::string::String>,
+            /// Identifies the scrollable of this form among those of other forms, also of the
+            /// same type
+            scrollable_id: ::iced::widget::scrollable::Id,
+            #search_field
+            #nav_field
             #form_fields
         }
         impl ::std::default::Default for #form_name {
             fn default() -> Self {
-                let def = #name::default();
-                Self{
-                    builder: ::std::default::Default::default(),
-                    #form_default
-                }
+                #skip_exprs
+                <Self as ::iced_form::Form>::from_value(def)
             }
         }
         impl #form_name{
-            #vis fn view_nested<'a, Theme>(&'a self)->::iced::Element<'a, #form_message,Theme>
+            fn grid<'a, Theme>(&'a self, query: &str)->::iced_form::layout::Grid<'a, #form_message, Theme, ::iced::Renderer>
             where Theme: ::iced_form::Catalog + 'a
             {
-                iced::widget::column![
-                    #form_view
-                ].into()
-
+                let grid = ::iced_form::layout::Grid::new(#label_position);
+                #form_view
+                #computed
+                grid
+            }
+            fn nav_node(&self, label: &str, path: &str) -> ::iced_form::nav::Node {
+                let children: ::std::vec::Vec<::std::vec::Vec<::iced_form::nav::Node>> =
+                    ::std::vec![#nav_children];
+                ::iced_form::nav::Node::new(
+                    label,
+                    path,
+                    self.build().is_some(),
+                    children.into_iter().flatten().collect(),
+                )
             }
             #vis fn build(&self)-> ::std::option::Option<#name>{
-                self.builder.build().ok()
+                self.shown_builder().build().ok().filter(|val| self.check(val).is_empty())
+            }
+            /// The builder with hidden fields set to their default value and the others to the
+            /// value they show
+            fn shown_builder(&self) -> #builder_name {
+                #shown_builder
+            }
+            /// The errors of the validators of the struct, but those of hidden fields which are
+            /// built with their default value
+            fn check(&self, val: &#name) -> ::iced_form::validate::PathErrors {
+                let errors: ::iced_form::validate::PathErrors =
+                    ::std::vec::Vec::new().into_iter()#(.chain(#checks))*.collect();
+                #hide_errors
+                errors
+            }
+            fn shown_error(&self, field: &str) -> ::std::option::Option<&str> {
+                let visible = #errors_visible;
+                ::iced_form::validate::find(&self.applied_errors, field).or_else(|| {
+                    [&self.field_errors, &self.parent_errors]
+                        .into_iter()
+                        .filter(|_| visible)
+                        .find_map(|errors| ::iced_form::validate::find(errors, field))
+                })
+            }
+            #blurred
+            fn field_error(&self, field: &str) -> ::std::option::Option<&str> {
+                [&self.field_errors, &self.parent_errors, &self.applied_errors]
+                    .into_iter()
+                    .find_map(|errors| ::iced_form::validate::find(errors, field))
+            }
+            /// The field edited by `message`, messages that only report on a field, e.g. that it
+            /// lost focus, edit none
+            fn edited_field(&self, message: &#form_message) -> ::std::option::Option<&'static str> {
+                match message {
+                    #edited_fields
+                    _ => None,
+                }
+            }
+            /// Runs the validators of the struct on the value shown, passing their errors on to
+            /// the nested forms
+            fn update_errors(&mut self) {
+                self.field_errors = self
+                    .shown_builder()
+                    .build()
+                    .map(|val| self.check(&val))
+                    .unwrap_or_default();
+                self.push_errors();
+            }
+            fn push_errors(&mut self) {
+                #push_errors
+            }
+            /// Makes nested forms read-only along with their field
+            fn sync_readonly(&mut self) {
+                #sync_readonly
+            }
+            /// The fields without a title or Submit button, to be shown within an enclosing form
+            #vis fn view_nested<'a, Theme>(&'a self)->::iced::Element<'a, #form_message,Theme>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                self.grid("").into()
             }
             #vis fn view<'a, Theme>(&'a self)->iced::Element<'a, #form_message, Theme>
             where Theme: ::iced_form::Catalog + 'a
             {
-                let submit = ::iced::widget::button("Submit").on_press_maybe(self.builder.build().ok().map(|val|#form_message::#name(val)));
-                iced::widget::column![
-                    #form_view
-                    submit,
-                ].into()
+                let breakpoints = #breakpoints;
+                ::iced::widget::responsive(move |size| {
+                    let grid = self.grid(#query).columns(breakpoints.columns(size.width));
+                    #nav_content
+                    let fields = ::iced::widget::scrollable(content)
+                        .id(self.scrollable_id.clone())
+                        .height(::iced::Length::Fill);
+                    let submit = ::iced::widget::button("Submit").on_press(
+                        self.build().map(#form_message::#name).unwrap_or(#form_message::SubmitAttempted)
+                    );
+                    let body = iced::widget::column![
+                        #search_input
+                        #nav_breadcrumbs
+                        fields,
+                        submit,
+                    ];
+                    #body
+                }).into()
+            }
+            /// Shows the field at the dotted `path`, giving its path within the fields shown
+            fn reveal<'p>(&mut self, path: &'p str) -> &'p str {
+                #nav_reveal
+            }
+            #vis fn scroll_to(&mut self, path: &str) -> ::iced::Command<#form_message> {
+                let path = self.reveal(path);
+                ::iced_form::scroll::scroll_to(self.scrollable_id.clone(), path)
             }
             #vis fn update(&mut self, message:#form_message)-> ::iced::Command<#form_message>{
-                match message {
+                if let Some(field) = self.edited_field(&message) {
+                    ::iced_form::validate::clear(&mut self.applied_errors, field);
+                    self.submit_queued = false;
+                }
+                let cmd = match message {
                     #form_update
+                    #search_update
+                    #nav_update
+                    #form_message::SubmitAttempted if ::iced_form::Form::is_pending(self) => {
+                        self.submit_queued = true;
+                        ::iced::Command::none()
+                    }
+                    #form_message::SubmitAttempted => {
+                        ::iced_form::Form::submit_attempted(self);
+                        self.update_errors();
+                        match ::iced_form::Form::first_invalid(self) {
+                            Some(path) => {
+                                let path = self.reveal(&path);
+                                ::iced::Command::batch([
+                                    ::iced_form::scroll::scroll_to(self.scrollable_id.clone(), path),
+                                    ::iced_form::focus::focus(self.scrollable_id.clone(), path),
+                                ])
+                            }
+                            None => ::iced::Command::none(),
+                        }
+                    }
                     _ => ::iced::Command::none(),
+                };
+                self.update_errors();
+                self.sync_readonly();
+                if self.submit_queued && !::iced_form::Form::is_pending(self) {
+                    self.submit_queued = false;
+                    let submit = match self.build() {
+                        Some(val) => ::iced::Command::perform(::std::future::ready(val), #form_message::#name),
+                        None => self.update(#form_message::SubmitAttempted),
+                    };
+                    return ::iced::Command::batch([cmd, submit]);
+                }
+                cmd
+            }
+        }
+        impl ::iced_form::Form for #form_name {
+            type Value = #name;
+            type Message = #form_message;
+            /// Bools and skipped fields are kept in the builder, the others in their own forms
+            fn from_value(def: #name) -> Self {
+                let mut form = Self{
+                    builder: ::std::default::Default::default(),
+                    field_errors: ::std::vec::Vec::new(),
+                    parent_errors: ::std::vec::Vec::new(),
+                    applied_errors: ::std::vec::Vec::new(),
+                    submitted: false,
+                    submit_queued: false,
+                    readonly: false,
+                    readonly_fields: ::std::collections::BTreeSet::new(),
+                    scrollable_id: ::iced::widget::scrollable::Id::unique(),
+                    #search_default
+                    #nav_default
+                    #form_default
+                };
+                #skip_defaults
+                #bool_defaults
+                #pickers
+                form.builder = form.shown_builder();
+                form.update_errors();
+                form.sync_readonly();
+                form
+            }
+            fn init(&mut self) -> ::iced::Command<#form_message> {
+                #init
+            }
+            fn build(&self) -> ::std::option::Option<#name> {
+                #form_name::build(self)
+            }
+            fn update(&mut self, message: #form_message) -> ::iced::Command<#form_message> {
+                #form_name::update(self, message)
+            }
+            fn view_nested<'a, Theme>(&'a self)->::iced::Element<'a, #form_message,Theme>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                #form_name::view_nested(self)
+            }
+            fn view_columns<'a, Theme>(&'a self, columns: usize)->::iced::Element<'a, #form_message,Theme>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                self.grid("").columns(columns).into()
+            }
+            fn view_filtered<'a, Theme>(&'a self, query: &str)->::std::option::Option<::iced::Element<'a, #form_message,Theme>>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                let grid = self.grid(query);
+                (!grid.is_empty()).then(|| grid.into())
+            }
+            fn view_at<'a, Theme>(&'a self, path: &str)->::std::option::Option<::iced::Element<'a, #form_message,Theme>>
+            where Theme: ::iced_form::Catalog + 'a
+            {
+                if path.is_empty() {
+                    return Some(::iced_form::Form::view_nested(self));
+                }
+                match ::iced_form::nav::split(path) {
+                    #view_at
+                    _ => None,
+                }
+            }
+            fn nav_tree(&self, label: &str, path: &str) -> ::std::option::Option<::iced_form::nav::Node> {
+                Some(self.nav_node(label, path))
+            }
+            fn set_errors(&mut self, errors: ::iced_form::validate::PathErrors) {
+                self.parent_errors = errors;
+                self.push_errors();
+            }
+            fn apply_errors(&mut self, errors: ::iced_form::validate::FormErrors) {
+                #apply_errors
+                self.applied_errors = errors.outside(&[#(#nested_ids),*]);
+            }
+            fn is_edit(&self, message: &#form_message) -> bool {
+                self.edited_field(message).is_some()
+            }
+            fn is_pending(&self) -> bool {
+                #is_pending
+            }
+            fn submit_attempted(&mut self) {
+                self.submitted = true;
+                #submit_attempted
+            }
+            fn first_invalid(&self) -> ::std::option::Option<::std::string::String> {
+                #first_invalid
+                None
+            }
+            fn set_readonly(&mut self, path: &str, readonly: bool) {
+                match ::iced_form::nav::split(path) {
+                    ("", _) => self.readonly = readonly,
+                    #set_readonly_nested
+                    (field, _) => {
+                        if readonly {
+                            self.readonly_fields.insert(field.to_string());
+                        } else {
+                            self.readonly_fields.remove(field);
+                        }
+                    }
                 }
+                self.sync_readonly();
             }
         }
     };
     TokenStream::from(expanded)
 }
 
+/// The validators run on the built struct, each giving `iced_form::validate::PathErrors`
+fn validation_checks(
+    input: &DeriveInput,
+    data: &DataStruct,
+    attrs: &FormAttrs,
+) -> Vec<proc_macro2::TokenStream> {
+    let mut checks = Vec::new();
+    if let Some(validate) = &attrs.validate {
+        checks.push(quote! {::iced_form::validate::field_errors(#validate(val))});
+    }
+    if cfg!(feature = "validator") && has_attr(input, data, "validate") {
+        checks.push(quote! {::iced_form::validate::validator::errors(val)});
+    }
+    if cfg!(feature = "garde") && has_attr(input, data, "garde") {
+        checks.push(quote! {::iced_form::validate::garde::errors(val)});
+    }
+    checks
+}
+
+/// Whether the struct or any of its fields carries `#[name(...)]`
+fn has_attr(input: &DeriveInput, data: &DataStruct, name: &str) -> bool {
+    input
+        .attrs
+        .iter()
+        .chain(data.fields.iter().flat_map(|f| &f.attrs))
+        .any(|attr| attr.path().is_ident(name))
+}
+
+/// The `nav_tree` children and `view_at` match arms for every nested form
+fn gen_navigation(
+    fields: &[&Field],
+    field_attrs: &[FieldAttrs],
+    form_message: &proc_macro2::Ident,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let nested = fields
+        .iter()
+        .zip(field_attrs)
+        .filter(|(f, _)| !is_bool(&f.ty) && !is_form_field_type(&f.ty));
+    let nav_children = nested.clone().map(|(f, attrs)| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        let label = attrs.label(f);
+        match label.is_empty() {
+            // Unlabeled forms are shown in place, so are their nested forms
+            true => quote_spanned! {f.span()=>
+                ::iced_form::Form::nav_tree(&self.#name, "", &::iced_form::nav::join(path, #id))
+                    .map(|node| node.children)
+                    .unwrap_or_default()
+            },
+            false => quote_spanned! {f.span()=>
+                ::iced_form::Form::nav_tree(&self.#name, #label, &::iced_form::nav::join(path, #id))
+                    .into_iter()
+                    .collect()
+            },
+        }
+    });
+    let view_at = nested.map(|(f, _)| {
+        let name = &f.ident;
+        let id = name.clone().unwrap().to_string();
+        let pascal_name = format_ident!("{}", id.to_case(Case::Pascal));
+        quote_spanned! {f.span()=>
+            (#id, rest) => ::iced_form::Form::view_at(&self.#name, rest).map(|view| view.map(#form_message::#pascal_name)),
+        }
+    });
+    (quote! {#(#nav_children ,)*}, quote! {#(#view_at)*})
+}
+
 fn gen_fields(
-    fields: &Fields,
+    fields: &[&Field],
+    field_attrs: &[FieldAttrs],
+    visible: &[Option<proc_macro2::TokenStream>],
+    editable: &[proc_macro2::TokenStream],
     form_message: &proc_macro2::Ident,
+    show_errors: &proc_macro2::TokenStream,
 ) -> (
     proc_macro2::TokenStream,
     proc_macro2::TokenStream,
@@ -73,108 +877,178 @@ fn gen_fields(
     proc_macro2::TokenStream,
     proc_macro2::TokenStream,
 ) {
-    match fields {
-        Fields::Named(fields) => {
-            let enum_variants = fields.named.iter().map(|f| {
-                let name = &f.ident;
-                let ty = &f.ty;
-                let pascal_name = format_ident!(
-                    "{}",
-                    name.clone().unwrap().to_string().to_case(Case::Pascal)
-                );
-                if is_bool(ty) {
-                    quote_spanned! {f.span()=> #pascal_name(#ty) }
-                } else if is_form_field_type(ty) {
-                    quote_spanned! {f.span()=> #pascal_name(iced_form::form_field::Message<#ty>) }
-                } else {
-                    let (mut sub_path, sub_ty) = get_type_ident(ty);
-                    let sub_message = form_message_name(&sub_ty);
-                    sub_path.segments.push(sub_message.into());
-                    quote_spanned! {f.span()=>#pascal_name(#sub_path)}
-                }
-            });
-            let form_fields = fields.named.iter().filter(|f| !is_bool(&f.ty)).map(|f| {
-                let name = &f.ident;
-                let ty = &f.ty;
-                if is_form_field_type(ty) {
-                    quote_spanned! {f.span()=> #name: ::iced_form::form_field::FormField<#ty> }
-                } else {
-                    let (mut form_path, ty_ident) = get_type_ident(ty);
-                    form_path.segments.push(form_name(&ty_ident).into());
-                    quote_spanned! {f.span()=>#name:#form_path }
+    let enum_variants = fields.iter().zip(field_attrs).map(|(f, attrs)| {
+        let name = &f.ident;
+        let ty = &f.ty;
+        let pascal_name = format_ident!(
+            "{}",
+            name.clone().unwrap().to_string().to_case(Case::Pascal)
+        );
+        if is_bool(ty) {
+            quote_spanned! {f.span()=> #pascal_name(#ty) }
+        } else if attrs.flags {
+            quote_spanned! {f.span()=> #pascal_name(::iced_form::flags::Message) }
+        } else if is_form_field_type(ty) {
+            quote_spanned! {f.span()=> #pascal_name(iced_form::form_field::Message<#ty>) }
+        } else {
+            let sub_message = form_message_type(ty);
+            quote_spanned! {f.span()=>#pascal_name(#sub_message)}
+        }
+    });
+    let form_fields = fields
+        .iter()
+        .zip(field_attrs)
+        .filter(|(f, _)| !is_bool(&f.ty))
+        .map(|(f, attrs)| {
+            let name = &f.ident;
+            let ty = &f.ty;
+            if attrs.flags {
+                quote_spanned! {f.span()=> #name: ::iced_form::flags::FlagsForm<#ty> }
+            } else if is_form_field_type(ty) {
+                quote_spanned! {f.span()=> #name: ::iced_form::form_field::FormField<#ty> }
+            } else {
+                let form = form_type(ty);
+                quote_spanned! {f.span()=>#name:#form }
+            }
+        });
+    let form_default = fields
+        .iter()
+        .zip(field_attrs)
+        .filter(|(f, _)| !is_bool(&f.ty))
+        .map(|(f, attrs)| {
+            let name = &f.ident;
+            let label = attrs.label(f);
+            let validators = &attrs.validators;
+            let validate_async = attrs.validate_async.iter();
+            if is_form_field_type(&f.ty) {
+                quote_spanned! {f.span()=>
+                    #name: ::iced_form::form_field::FormField::new(#label)
+                        #(.validate(#validators))*
+                        #(.validate_async(#validate_async))*
+                        .show_errors(#show_errors)
+                        .with_value(def.#name)
                 }
-            });
-            let form_default = fields.named.iter().filter(|f| !is_bool(&f.ty)).map(|f|{
-                    let name = &f.ident;
-                    let title_name = name.clone().unwrap().to_string().to_case(Case::Title);
-                    if is_form_field_type(&f.ty) {
-                        quote_spanned! {f.span()=>#name: ::iced_form::form_field::FormField::new(#title_name).with_value(def.#name)}
-                    } else{
-                        quote_spanned! {f.span()=>#name: ::std::default::Default::default()}
-                    }
-                });
-            let form_view = fields.named.iter().map(|f| {
+            } else {
+                quote_spanned! {f.span()=>#name: ::iced_form::Form::from_value(def.#name)}
+            }
+        });
+    let form_view = fields.iter().zip(field_attrs).zip(visible).zip(editable).map(|(((f, attrs), visible), editable)| {
                 let name = &f.ident;
                 let pascal_name = format_ident!(
                     "{}",
                     name.clone().unwrap().to_string().to_case(Case::Pascal)
                 );
-                if is_bool(&f.ty) {
-                    let title_name = name.clone().unwrap().to_string().to_case(Case::Title);
-                    quote_spanned! {f.span()=>
-                        ::iced::widget::toggler(
-                            Some(#title_name.to_owned()),
-                            self.builder.#name.unwrap_or(false),
-                            #form_message::#pascal_name)
-                    }
+                let title_name = attrs.label(f);
+                let id = name.clone().unwrap().to_string();
+                let (input, filtered) = if is_bool(&f.ty) {
+                    (
+                        // The toggler has no disabled state, read-only bools are shown as text
+                        quote_spanned! {f.span()=>
+                            match (#editable, self.builder.#name.unwrap_or(false)) {
+                                (true, toggled) => ::iced::Element::from(::iced::widget::toggler(
+                                    None,
+                                    toggled,
+                                    #form_message::#pascal_name)),
+                                (false, true) => ::iced::widget::text("On").into(),
+                                (false, false) => ::iced::widget::text("Off").into(),
+                            }
+                        },
+                        quote! {None},
+                    )
                 } else if is_form_field_type(&f.ty){
-                    quote_spanned! {f.span()=> self.#name.view().map(#form_message::#pascal_name)}
+                    (
+                        quote_spanned! {f.span()=> self.#name.view_input_editable(#editable).map(#form_message::#pascal_name)},
+                        quote! {None},
+                    )
                 } else{
-                    quote_spanned! {f.span()=> self.#name.view_nested().map(#form_message::#pascal_name)}
-                }
-            });
-            let form_update = fields.named.iter().map(|f| {
-                let name = &f.ident;
-                let pascal_name = format_ident!(
-                    "{}",
-                    name.clone().unwrap().to_string().to_case(Case::Pascal)
-                );
-                if is_bool(&f.ty) {
-                    quote_spanned! {f.span()=>
-                        #form_message::#pascal_name(val)=>{
-                            self.builder.#name(val);
-                            ::iced::Command::none()
-                        }
-                    }
-                } else if is_form_field_type(&f.ty) {
-                    quote_spanned! {f.span()=>
-                        #form_message::#pascal_name(message)=>{
-                            if let ::iced_form::form_field::Message::Value((ref val,_))=message{
-                                self.builder.#name(val.clone());
-                            }
-                            self.#name.update(message).map(#form_message::#pascal_name)
-                        }
-                    }
-                } else {
-                    quote_spanned! {f.span()=>
-                        #form_message::#pascal_name(message) => {
-                            let cmd = self.#name.update(message).map(#form_message::#pascal_name);
-                            if let Some(val) = self.#name.build(){
-                                self.builder.#name(val);
-                            }
-                            cmd
+                    (
+                        quote_spanned! {f.span()=> ::iced_form::Form::view_nested(&self.#name).map(#form_message::#pascal_name)},
+                        quote_spanned! {f.span()=>
+                            ::iced_form::Form::view_filtered(&self.#name, ::iced_form::search::descend(query, #id))
+                                .map(|input| input.map(#form_message::#pascal_name))
+                        },
+                    )
+                };
+                let span = attrs.span.unwrap_or(1);
+                let doc = &attrs.doc;
+                let row = quote_spanned! {f.span()=>
+                    {
+                        let input: ::std::option::Option<::iced::Element<'a, #form_message, Theme>> =
+                            if ::iced_form::search::matches(query, &[#title_name, #id, #doc]) {
+                                Some(#input)
+                            } else {
+                                #filtered
+                            };
+                        match input {
+                            Some(input) => grid.push_row(
+                                ::iced_form::layout::Row::new(
+                                    ::iced_form::search::label(#title_name, query),
+                                    ::iced_form::validate::with_error(input, self.shown_error(#id)),
+                                )
+                                    .id(#id)
+                                    .span(#span)
+                            ),
+                            None => grid,
                         }
                     }
+                };
+                match visible {
+                    Some(visible) => quote_spanned! {f.span()=>
+                        let grid = if #visible { #row } else { grid };
+                    },
+                    None => quote_spanned! {f.span()=> let grid = #row;},
                 }
             });
-            (
-                quote! {#(#enum_variants ,)*},
-                quote! {#(#form_fields ,)*},
-                quote! {#(#form_default ,)*},
-                quote! {#(#form_view ,)*},
-                quote! {#(#form_update ,)*},
-            )
+    let form_update = fields.iter().zip(editable).map(|(f, editable)| {
+        let name = &f.ident;
+        let pascal_name = format_ident!(
+            "{}",
+            name.clone().unwrap().to_string().to_case(Case::Pascal)
+        );
+        if is_bool(&f.ty) {
+            quote_spanned! {f.span()=>
+                #form_message::#pascal_name(val) if #editable => {
+                    self.builder.#name(val);
+                    ::iced::Command::none()
+                }
+            }
+        } else if is_form_field_type(&f.ty) {
+            quote_spanned! {f.span()=>
+                // Read-only fields still take the results of their asynchronous validator
+                #form_message::#pascal_name(
+                    ::iced_form::form_field::Message::Input { .. } | ::iced_form::form_field::Message::Value(_)
+                ) if !#editable => ::iced::Command::none(),
+                #form_message::#pascal_name(message)=>{
+                    let cmd = self.#name.update(message).map(#form_message::#pascal_name);
+                    // Values failing a validator are kept out of the builder, blocking Submit
+                    self.builder.#name = self.#name.value().cloned();
+                    cmd
+                }
+            }
+        } else {
+            quote_spanned! {f.span()=>
+                #form_message::#pascal_name(message) => {
+                    let cmd = ::iced_form::Form::update(&mut self.#name, message).map(#form_message::#pascal_name);
+                    if let Some(val) = ::iced_form::Form::build(&self.#name) {
+                        self.builder.#name(val);
+                    }
+                    cmd
+                }
+            }
         }
-        _ => unimplemented!(),
+    });
+    (
+        quote! {#(#enum_variants ,)*},
+        quote! {#(#form_fields ,)*},
+        quote! {#(#form_default ,)*},
+        quote! {#(#form_view)*},
+        quote! {#(#form_update ,)*},
+    )
+}
+
+fn quote_option(val: Option<usize>) -> proc_macro2::TokenStream {
+    match val {
+        Some(val) => quote! {Some(#val)},
+        None => quote! {None},
     }
 }
@@ -1,87 +1,293 @@
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::{quote, quote_spanned};
-use syn::{spanned::Spanned, DataEnum, DeriveInput};
+use syn::{spanned::Spanned, DataEnum, DeriveInput, Ident};
 
-use crate::{form_message_name, form_name};
+use crate::{
+    attrs::{FormAttrs, VariantAttrs},
+    default_of, form_message_name, form_name,
+};
 
-pub fn derive_for_unit_enum(input: &DeriveInput, data: &DataEnum) -> TokenStream {
+pub fn derive_for_unit_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+    attrs: &FormAttrs,
+) -> TokenStream {
     let vis = &input.vis;
     let name = &input.ident;
     let form_name = form_name(name);
     let form_message = form_message_name(name);
     let title_name = name.clone().to_string().to_case(Case::Title);
-    let (num_variants, variants, variant_displays) = gen_variants(data);
-    let expanded = quote! {
-        impl #name {
-            #vis const ALL: [#name;#num_variants] = [
-                #variants
-            ];
-        }
-        impl ::std::fmt::Display for #name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(f,
-                    "{}",
-                    match self {
-                        #variant_displays
-                    }
-                )
+    let label_position = attrs.label_position.to_tokens();
+    let picker = attrs.picker.to_tokens();
+    let default = default_of(name);
+    let (num_variants, variants, variant_displays, deprecated, skipped) =
+        match gen_variants(name, data, attrs) {
+            Ok(variants) => variants,
+            Err(err) => return err.to_compile_error().into(),
+        };
+    // A hand-written `ALL` may list skipped variants, they are left out of the picker all the same
+    let options = match (attrs.no_all, skipped.is_empty()) {
+        (true, false) => quote! {
+            ::std::vec::Vec::from(#name::ALL)
+                .into_iter()
+                .filter(|variant| !matches!(variant, #(#skipped)|*))
+                .collect::<::std::vec::Vec<_>>()
+        },
+        _ => quote! {::std::vec::Vec::from(#name::ALL)},
+    };
+    let all = match attrs.no_all {
+        true => quote! {},
+        false => quote! {
+            #[allow(deprecated)]
+            impl #name {
+                #vis const ALL: [#name;#num_variants] = [
+                    #variants
+                ];
             }
-        }
+        },
+    };
+    let display = match attrs.use_display {
+        true => quote! {},
+        false => quote! {
+            #[allow(deprecated)]
+            impl ::std::fmt::Display for #name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f,
+                        "{}",
+                        match self {
+                            #variant_displays
+                        }
+                    )
+                }
+            }
+        },
+    };
+    let expanded = quote! {
+        #all
+        #display
         #[derive(Clone, Debug)]
         #vis enum #form_message{
             #name(#name),
+            /// Submit was pressed with the variant picked
+            Submit(#name),
+            /// Submit was pressed while no variant is picked
+            SubmitAttempted,
         }
-        #[derive(::std::default::Default)]
         #vis struct #form_name{
             selected: Option<#name>,
+            readonly: bool,
+            choice: ::iced_form::choice::Choice<#name>,
+            /// Id of the scrollable holding the picker, unique per form
+            scrollable_id: ::iced::widget::scrollable::Id,
+        }
+        #[allow(deprecated)]
+        impl ::std::default::Default for #form_name {
+            fn default() -> Self {
+                Self {
+                    // The `Default` of the enum, if any, is preselected
+                    selected: #default,
+                    readonly: false,
+                    choice: ::iced_form::choice::Choice::new(#picker, #options)#deprecated,
+                    scrollable_id: ::iced::widget::scrollable::Id::unique(),
+                }
+            }
         }
     impl #form_name {
-        #vis fn view_nested<'a, Theme>(&'a self)->::iced::Element<'a, #form_message, Theme>
-        where Theme: ::iced_form::Catalog + 'a
-        {
-            ::iced::widget::row![
-                ::iced::widget::text(#title_name),
-                ::iced::widget::pick_list(#name::ALL,self.selected.clone(),#form_message::#name)
-            ].into()
+        /// Overrides the widget picking the variant, as given on the field of the enclosing form
+        #[doc(hidden)]
+        #vis fn set_picker(&mut self, picker: ::iced_form::choice::Picker) {
+            self.choice.set_picker(picker);
         }
         #vis fn build(&self) -> ::std::option::Option<#name> {
             self.selected.clone()
         }
+        /// The picker without a title or Submit button, to be shown within an enclosing form
+        #vis fn view_nested<'a, Theme>(&'a self)->::iced::Element<'a, #form_message, Theme>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            self.choice.view(!self.readonly, self.selected.as_ref(), #form_message::#name)
+        }
         #vis fn view<'a, Theme>(&'a self)->::iced::Element<'a, #form_message, Theme>
         where Theme: ::iced_form::Catalog + 'a
         {
-            self.view_nested()
+            let fields = ::iced::widget::scrollable(
+                ::iced_form::layout::Grid::new(#label_position)
+                    .push(::iced::widget::text(#title_name), ::iced_form::Form::view_nested(self)),
+            )
+            .id(self.scrollable_id.clone())
+            .height(::iced::Length::Fill);
+            let submit = ::iced::widget::button("Submit").on_press(
+                self.build().map(#form_message::Submit).unwrap_or(#form_message::SubmitAttempted)
+            );
+            ::iced::widget::column![fields, submit].into()
         }
         #vis fn update(&mut self, message:#form_message)-> ::iced::Command<#form_message> {
             match message {
                 #form_message::#name(val)=>{
-                    self.selected=Some(val);
+                    if !self.readonly {
+                        self.selected=Some(val);
+                    }
                     ::iced::Command::none()
                 }
+                // The picker has no input to focus, it is scrolled back into view
+                #form_message::SubmitAttempted => match ::iced_form::Form::first_invalid(self) {
+                    Some(path) => ::iced_form::scroll::scroll_to(self.scrollable_id.clone(), &path),
+                    None => ::iced::Command::none(),
+                },
+                #form_message::Submit(_) => ::iced::Command::none(),
+            }
+        }
+    }
+    impl ::iced_form::choice::Variants for #name {
+        fn variants() -> ::std::vec::Vec<Self> {
+            #options
+        }
+    }
+    impl ::iced_form::EnumForm for #form_name {
+        type Kind = #name;
+        fn kind(&self) -> ::std::option::Option<#name> {
+            self.selected.clone()
+        }
+        fn pick(&mut self, kind: #name) -> ::iced::Command<#form_message> {
+            #form_name::update(self, #form_message::#name(kind))
+        }
+        fn picker(&self) -> ::iced_form::choice::Picker {
+            self.choice.picker()
+        }
+        fn view_fields<'a, Theme>(&'a self)->::std::option::Option<::iced::Element<'a, #form_message, Theme>>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            None
+        }
+        fn view_fields_filtered<'a, Theme>(&'a self, _query: &str)->::std::option::Option<::iced::Element<'a, #form_message, Theme>>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            None
+        }
+    }
+    impl ::iced_form::Form for #form_name {
+        type Value = #name;
+        type Message = #form_message;
+        fn from_value(value: #name) -> Self {
+            Self {
+                selected: Some(value),
+                ..::std::default::Default::default()
             }
         }
+        fn build(&self) -> ::std::option::Option<#name> {
+            #form_name::build(self)
+        }
+        fn update(&mut self, message: #form_message) -> ::iced::Command<#form_message> {
+            #form_name::update(self, message)
+        }
+        fn view_nested<'a, Theme>(&'a self)->::iced::Element<'a, #form_message, Theme>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            #form_name::view_nested(self)
+        }
+        fn view_filtered<'a, Theme>(&'a self, query: &str)->::std::option::Option<::iced::Element<'a, #form_message, Theme>>
+        where Theme: ::iced_form::Catalog + 'a
+        {
+            self.choice
+                .options()
+                .iter()
+                .any(|variant| ::iced_form::search::matches(query, &[&variant.to_string()]))
+                .then(|| ::iced_form::Form::view_nested(self))
+        }
+        /// Keeps the selection from changing, the path is ignored as the enum has no fields
+        fn set_readonly(&mut self, _path: &str, readonly: bool) {
+            self.readonly = readonly;
+        }
+        fn first_invalid(&self) -> ::std::option::Option<::std::string::String> {
+            self.selected.is_none().then(::std::string::String::new)
+        }
     }
     };
     TokenStream::from(expanded)
 }
 
-fn gen_variants(data: &DataEnum) -> (usize, proc_macro2::TokenStream, proc_macro2::TokenStream) {
-    let variants = data.variants.iter().map(|v| {
-        let name = &v.ident;
-        quote_spanned! {v.span()=>Self::#name}
-    });
-    let variant_displays = data.variants.iter().map(|v| {
-        let name = &v.ident;
-        let title_name = name.clone().to_string().to_case(Case::Title);
-        quote_spanned! {v.span()=>
-            Self::#name=>#title_name
-        }
-    });
+fn gen_variants(
+    name: &Ident,
+    data: &DataEnum,
+    attrs: &FormAttrs,
+) -> syn::Result<(
+    usize,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    Vec<proc_macro2::TokenStream>,
+)> {
+    let var_attrs = data
+        .variants
+        .iter()
+        .map(|v| VariantAttrs::parse(&v.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let skipped = data
+        .variants
+        .iter()
+        .zip(&var_attrs)
+        .filter(|(_, var_attrs)| var_attrs.skip)
+        .map(|(v, _)| {
+            let var_name = &v.ident;
+            quote_spanned! {v.span()=>#name::#var_name}
+        })
+        .collect();
+    // Skipped variants are left out of `ALL`, and so of the picker
+    let variants: Vec<_> = data
+        .variants
+        .iter()
+        .zip(&var_attrs)
+        .filter(|(_, var_attrs)| !var_attrs.skip)
+        .map(|(v, _)| {
+            let name = &v.ident;
+            quote_spanned! {v.span()=>Self::#name}
+        })
+        .collect();
+    let variant_displays = data
+        .variants
+        .iter()
+        .zip(var_attrs)
+        .map(|(v, var_attrs)| {
+            let name = &v.ident;
+            let title_name = match var_attrs.rename {
+                Some(rename) if attrs.use_display => {
+                    return Err(syn::Error::new(
+                        rename.span(),
+                        "rename has no effect along with use_display",
+                    ))
+                }
+                Some(rename) => rename.value(),
+                None => name.clone().to_string().to_case(Case::Title),
+            };
+            Ok(quote_spanned! {v.span()=>
+                Self::#name=>#title_name
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    let deprecated: Vec<_> = data
+        .variants
+        .iter()
+        .filter(|v| {
+            v.attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("deprecated"))
+        })
+        .map(|v| {
+            let var_name = &v.ident;
+            quote_spanned! {v.span()=>#name::#var_name}
+        })
+        .collect();
+    let deprecated = match deprecated.is_empty() {
+        true => quote! {},
+        false => quote! {.deprecated([#(#deprecated),*])},
+    };
 
-    (
-        data.variants.len(),
+    Ok((
+        variants.len(),
         quote! {#(#variants ,)* },
         quote! {#(#variant_displays ,)* },
-    )
+        deprecated,
+        skipped,
+    ))
 }
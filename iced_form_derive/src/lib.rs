@@ -1,32 +1,41 @@
+mod attrs;
 mod de_enum;
 mod de_struct;
 mod de_unit_enum;
+use attrs::FormAttrs;
 use de_struct::derive_for_struct;
 use de_unit_enum::derive_for_unit_enum;
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
-use quote::format_ident;
-use syn::{parse_macro_input, Data, DataEnum, DeriveInput, Fields, Path, Type, TypePath};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, Data, DataEnum, DeriveInput, Fields, GenericArgument, Path, PathArguments,
+    Type, TypePath,
+};
 
-#[proc_macro_derive(FormBuilder)]
+#[proc_macro_derive(FormBuilder, attributes(form))]
 pub fn derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    let attrs = match FormAttrs::parse(&input.attrs) {
+        Ok(attrs) => attrs,
+        Err(err) => return err.to_compile_error().into(),
+    };
     match &input.data {
-        Data::Struct(data) => derive_for_struct(&input, data),
-        Data::Enum(data) => derive_for_enum(&input, data),
+        Data::Struct(data) => derive_for_struct(&input, data, &attrs),
+        Data::Enum(data) => derive_for_enum(&input, data, &attrs),
         Data::Union(_) => unimplemented!(),
     }
 }
 
-fn derive_for_enum(input: &DeriveInput, data: &DataEnum) -> TokenStream {
+fn derive_for_enum(input: &DeriveInput, data: &DataEnum, attrs: &FormAttrs) -> TokenStream {
     if data
         .variants
         .iter()
         .all(|f| matches!(f.fields, Fields::Unit))
     {
-        derive_for_unit_enum(input, data)
+        derive_for_unit_enum(input, data, attrs)
     } else {
-        de_enum::derive_for_enum(input, data)
+        de_enum::derive_for_enum(input, data, attrs)
     }
 }
 
@@ -37,6 +46,44 @@ fn form_name(ident: &Ident) -> Ident {
     format_ident!("{}Form", ident)
 }
 
+/// `Some` of the `Default` of `ty` if it implements `Default`, else `None`
+fn default_of(ty: &Ident) -> proc_macro2::TokenStream {
+    quote! {{
+        #[allow(unused_imports)]
+        use ::iced_form::preselect::{WithDefault as _, WithoutDefault as _};
+        (&::iced_form::preselect::Probe::<#ty>::new()).default_value()
+    }}
+}
+
+/// The form of a nested field of type `ty`, `Option<E>` is edited with an
+/// `iced_form::option::OptionForm` of the form of `E` and collections of `E` with an
+/// `iced_form::set::SetForm`
+fn form_type(ty: &Type) -> proc_macro2::TokenStream {
+    if let Some(inner) = option_inner(ty) {
+        let inner = form_type(inner);
+        return quote! {::iced_form::option::OptionForm<#inner>};
+    }
+    if let Some(inner) = set_inner(ty) {
+        // The form of a collection builds that very collection
+        return quote! {::iced_form::set::SetForm<#inner, #ty>};
+    }
+    let (mut path, ident) = get_type_ident(ty);
+    path.segments.push(form_name(&ident).into());
+    quote! {#path}
+}
+
+/// The message of [`form_type`]
+fn form_message_type(ty: &Type) -> proc_macro2::TokenStream {
+    if option_inner(ty).is_some() || set_inner(ty).is_some() {
+        let form = form_type(ty);
+        return quote! {<#form as ::iced_form::Form>::Message};
+    }
+    let (mut path, ident) = get_type_ident(ty);
+    path.segments.push(form_message_name(&ident).into());
+    quote! {#path}
+}
+
+/// The path and name of `ty`
 fn get_type_ident(ty: &Type) -> (Path, Ident) {
     match ty {
         Type::Path(TypePath { qself: None, path }) => {
@@ -51,6 +98,33 @@ fn get_type_ident(ty: &Type) -> (Path, Ident) {
     }
 }
 
+/// `E` of `Option<E>`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, &["Option"])
+}
+
+/// `E` of `HashSet<E>`, `BTreeSet<E>` or `Vec<E>`
+fn set_inner(ty: &Type) -> Option<&Type> {
+    generic_inner(ty, &["HashSet", "BTreeSet", "Vec"])
+}
+
+/// `E` of `ty` when it is one of the generic types `names`
+fn generic_inner<'a>(ty: &'a Type, names: &[&str]) -> Option<&'a Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let segment = path.segments.last()?;
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if names.iter().any(|name| segment.ident == name) => {
+            match args.args.first()? {
+                GenericArgument::Type(inner) => Some(inner),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 fn is_bool(ty: &Type) -> bool {
     get_type_ident(ty).1 == "bool"
 }
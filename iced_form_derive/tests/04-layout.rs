@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::Form;
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+#[form(label_position = "top")]
+enum Mode {
+    #[default]
+    Basic,
+    Advanced,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(label_position = "right")]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(label_position = "left", breakpoints = [600, 1000.5])]
+struct Config {
+    #[form(span = 3)]
+    name: String,
+    verbose: bool,
+    mode: Mode,
+    #[form(span = 2)]
+    proxy: Proxy,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+/// A form loaded with a value builds that very value
+fn forms_load_values() {
+    let config = Config {
+        name: "server".to_string(),
+        verbose: true,
+        mode: Mode::Advanced,
+        proxy: Proxy {
+            host: "localhost".to_string(),
+            port: 8080,
+        },
+    };
+    assert_eq!(ConfigForm::from_value(config.clone()).build(), Some(config));
+}
+
+/// Each form scrolls on its own, also next to another form of the same type
+fn scrollables_unique() {
+    let (first, second) = (ConfigForm::default(), ConfigForm::default());
+    assert_ne!(first.scrollable_id, second.scrollable_id);
+}
+
+fn main() {
+    forms_load_values();
+    scrollables_unique();
+}
@@ -0,0 +1,51 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::Form;
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(search)]
+struct Config {
+    /// Shown in the title bar
+    name: String,
+    verbose: bool,
+    proxy: Proxy,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+fn matches(form: &ConfigForm, query: &str) -> bool {
+    Form::view_filtered::<iced::Theme>(form, query).is_some()
+}
+
+/// Fields are found by label, dotted path or doc comment, whatever the case
+fn search_filters_fields() {
+    let form = ConfigForm::default();
+    assert!(matches(&form, ""));
+    assert!(matches(&form, "VERBOSE"));
+    assert!(matches(&form, "title bar"));
+    assert!(matches(&form, "port"));
+    assert!(matches(&form, "proxy.port"));
+    assert!(!matches(&form, "proxy.name"));
+    assert!(!matches(&form, "timeout"));
+}
+
+/// The query typed into the search box is kept for the view
+fn search_query_kept() {
+    let mut form = ConfigForm::default();
+    let _ = form.update(ConfigFormMessage::SearchQuery("port".to_string()));
+    assert_eq!(form.search_query, "port");
+}
+
+fn main() {
+    search_filters_fields();
+    search_query_kept();
+}
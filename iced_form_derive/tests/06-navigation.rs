@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Network {
+    interface: String,
+    proxy: Proxy,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Retry {
+    attempts: u8,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(navigation)]
+struct Settings {
+    name: String,
+    network: Network,
+    retry: Retry,
+}
+
+common::app!(SettingsForm, SettingsFormMessage);
+
+fn paths(node: &iced_form::nav::Node) -> Vec<&str> {
+    node.children
+        .iter()
+        .map(|node| node.path.as_str())
+        .collect()
+}
+
+/// The tree lists the nested forms by path, each valid once it and its nested forms build
+fn nav_tree_of_nested_forms() {
+    let mut form = SettingsForm::default();
+    let tree = form.nav_tree("Settings", "").unwrap();
+    assert_eq!(paths(&tree), ["network", "retry"]);
+    assert_eq!(paths(&tree.children[0]), ["network.proxy"]);
+    assert_eq!(tree.children[0].label, "Network");
+    assert!(tree.valid);
+
+    let _ = form.update(SettingsFormMessage::Network(NetworkFormMessage::Proxy(
+        ProxyFormMessage::Port(form_field::Message::Input {
+            input: "x".to_string(),
+            invalid_reason: "Expected u16".to_string(),
+        }),
+    )));
+    let tree = form.nav_tree("Settings", "").unwrap();
+    assert!(!tree.valid && !tree.children[0].valid && !tree.children[0].children[0].valid);
+    assert!(tree.children[1].valid);
+}
+
+/// Navigating shows a nested form on its own, fields outside of it are revealed by going back
+/// to the whole form
+fn navigate_and_reveal() {
+    let mut form = SettingsForm::default();
+    let _ = form.update(SettingsFormMessage::Navigate("network".to_string()));
+    assert_eq!(form.nav_selected, "network");
+    assert!(Form::view_at::<iced::Theme>(&form, "network.proxy").is_some());
+    assert!(Form::view_at::<iced::Theme>(&form, "name").is_none());
+    assert!(Form::view_at::<iced::Theme>(&form, "timeout").is_none());
+
+    let _ = form.scroll_to("network.proxy.port");
+    assert_eq!(form.nav_selected, "network");
+    let _ = form.scroll_to("retry.attempts");
+    assert_eq!(form.nav_selected, "");
+}
+
+fn main() {
+    nav_tree_of_nested_forms();
+    navigate_and_reveal();
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    #[form(validate(non_empty, length(max = 253), regex = "^[a-z0-9.-]+$"))]
+    host: String,
+    #[form(validate(range(min = 1, max = 65535), custom = not_reserved))]
+    port: u16,
+}
+
+fn not_reserved(port: &u16) -> Result<(), String> {
+    match port {
+        1..=1023 => Err("Reserved port".to_string()),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Limits {
+    #[form(validate(one_of = [-1, 1_000]))]
+    offset: i32,
+    #[form(validate(one_of = [1u8, 2u8]))]
+    level: u8,
+    #[form(validate(one_of = ["eu", "us"]))]
+    region: String,
+}
+
+common::app!(ProxyForm, ProxyFormMessage);
+
+fn proxy(host: &str, port: u16) -> Option<Proxy> {
+    ProxyForm::from_value(Proxy {
+        host: host.to_string(),
+        port,
+    })
+    .build()
+}
+
+/// Values failing a rule do not build, the reason is kept below the input
+fn validators_reject() {
+    assert!(proxy("localhost", 8080).is_some());
+    // Empty host and port 0 out of range
+    assert!(ProxyForm::default().build().is_none());
+    assert!(proxy("local host", 8080).is_none());
+    assert!(proxy(&"a".repeat(254), 8080).is_none());
+    assert!(proxy("localhost", 80).is_none());
+
+    let mut form = ProxyForm::default();
+    let _ = form.update(ProxyFormMessage::Port(form_field::Message::Value((
+        22,
+        "22".to_string(),
+    ))));
+    assert_eq!(form.port.invalid_reason(), Some("Reserved port"));
+}
+
+/// `one_of` compares the typed values, whatever the spelling of the literals
+fn one_of_compares_values() {
+    let limits = |offset, level, region: &str| {
+        LimitsForm::from_value(Limits {
+            offset,
+            level,
+            region: region.to_string(),
+        })
+        .build()
+    };
+    assert!(limits(-1, 2, "eu").is_some());
+    assert!(limits(1000, 1, "us").is_some());
+    assert!(limits(1, 1, "us").is_none());
+    assert!(limits(-1, 3, "us").is_none());
+    assert!(limits(-1, 1, "fr").is_none());
+}
+
+fn main() {
+    validators_reject();
+    one_of_compares_values();
+}
@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{validate::FieldErrors, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(validate = Price::check)]
+struct Price {
+    cost: f64,
+    tax: f64,
+}
+
+impl Price {
+    fn check(&self) -> Result<(), FieldErrors> {
+        match self.tax < self.cost {
+            true => Ok(()),
+            false => Err(vec![("tax", "Must be below the cost".to_string())]),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Network {
+    Direct,
+    #[form(validate = proxied_retries)]
+    Proxied {
+        proxy: Proxy,
+        retries: usize,
+    },
+}
+
+fn proxied_retries(network: &Network) -> Result<(), FieldErrors> {
+    match network {
+        Network::Proxied { proxy, retries } if proxy.port == 8080 && *retries > 3 => Err(vec![(
+            "retries",
+            "At most 3 retries through 8080".to_string(),
+        )]),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Span {
+    #[form(validate = ordered_span)]
+    Range(u32, u32),
+    Open,
+}
+
+fn ordered_span(span: &Span) -> Result<(), FieldErrors> {
+    match span {
+        Span::Range(from, to) if to < from => {
+            Err(vec![("1", "Must not be before the start".to_string())])
+        }
+        _ => Ok(()),
+    }
+}
+
+common::app!(PriceForm, PriceFormMessage);
+
+fn has_error(errors: &[(String, String)], path: &str) -> bool {
+    errors.iter().any(|(error_path, _)| error_path == path)
+}
+
+/// The errors of a struct validator keep the form from building, shown below the named field
+fn struct_validator_rejects() {
+    let price = Price {
+        cost: 10.0,
+        tax: 2.0,
+    };
+    assert_eq!(PriceForm::from_value(price.clone()).build(), Some(price));
+    let form = PriceForm::from_value(Price {
+        cost: 10.0,
+        tax: 20.0,
+    });
+    assert!(form.build().is_none());
+    assert!(has_error(&form.field_errors, "tax"));
+}
+
+/// Variant validators are given the enum, their errors are shown in the form of the variant
+fn variant_validator_rejects() {
+    let proxied = |retries| Network::Proxied {
+        proxy: Proxy {
+            host: "localhost".to_string(),
+            port: 8080,
+        },
+        retries,
+    };
+    assert_eq!(
+        NetworkForm::from_value(proxied(3)).build(),
+        Some(proxied(3))
+    );
+    let form = NetworkForm::from_value(proxied(5));
+    assert!(form.build().is_none());
+    let Some(NetworkEnumForm::Proxied(proxied)) = &form.form else {
+        panic!("the Proxied variant is not selected");
+    };
+    assert!(has_error(&proxied.field_errors, "retries"));
+}
+
+/// Validators of tuple variants name the fields by their index
+fn tuple_variant_errors() {
+    assert!(SpanForm::from_value(Span::Range(2, 5)).build().is_some());
+    let form = SpanForm::from_value(Span::Range(5, 2));
+    assert!(form.build().is_none());
+    let Some(SpanEnumForm::Range(range)) = &form.form else {
+        panic!("the Range variant is not selected");
+    };
+    assert!(has_error(&range.field_errors, "field_1"));
+}
+
+fn main() {
+    struct_validator_rejects();
+    variant_validator_rejects();
+    tuple_variant_errors();
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "validator")]
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::Form;
+use iced_form_derive::FormBuilder;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Interest {
+    Music,
+    Sports,
+    Gambling,
+}
+
+impl Validate for Interest {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if *self == Self::Gambling {
+            errors.add(
+                "",
+                ValidationError::new("adult").with_message("Not offered".into()),
+            );
+        }
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Validate, Default, PartialEq)]
+struct Address {
+    #[validate(length(min = 1, message = "Street is required"))]
+    street: String,
+    #[validate(range(min = 1000, max = 99999))]
+    zip: u32,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Validate, Default, PartialEq)]
+struct Account {
+    #[validate(length(min = 3, max = 20))]
+    name: String,
+    #[validate(range(min = 18))]
+    age: u8,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    interests: Vec<Interest>,
+}
+
+common::app!(AccountForm, AccountFormMessage);
+
+fn has_error(errors: &[(String, String)], path: &str) -> bool {
+    errors.iter().any(|(error_path, _)| error_path == path)
+}
+
+/// The rules of validator keep the form from building, their errors are shown at the paths of
+/// the fields, in nested forms and below the elements of collections
+fn validator_errors_mapped() {
+    let account = Account {
+        name: "Someone".to_string(),
+        age: 30,
+        address: Address {
+            street: "Main Street".to_string(),
+            zip: 12345,
+        },
+        interests: vec![Interest::Music],
+    };
+    assert_eq!(
+        AccountForm::from_value(account.clone()).build(),
+        Some(account.clone())
+    );
+
+    let form = AccountForm::from_value(Account {
+        age: 17,
+        interests: vec![Interest::Music, Interest::Gambling],
+        ..account.clone()
+    });
+    assert!(form.build().is_none());
+    assert!(has_error(&form.field_errors, "age"));
+    assert!(form
+        .field_errors
+        .contains(&("interests[1]".to_string(), "Not offered".to_string())));
+
+    let form = AccountForm::from_value(Account {
+        address: Address {
+            zip: 1,
+            ..account.address.clone()
+        },
+        ..account
+    });
+    assert!(form.build().is_none());
+    assert!(form.address.build().is_none());
+    assert!(has_error(&form.address.field_errors, "zip"));
+}
+
+fn main() {
+    validator_errors_mapped();
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "garde")]
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use garde::Validate;
+use iced_form::Form;
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Validate, Default, PartialEq)]
+struct Address {
+    #[garde(length(min = 1))]
+    street: String,
+    #[garde(range(min = 1000, max = 99999))]
+    zip: u32,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Validate, Default, PartialEq)]
+struct Account {
+    #[garde(length(min = 3, max = 20))]
+    name: String,
+    #[garde(range(min = 18))]
+    age: u8,
+    #[garde(dive)]
+    address: Address,
+}
+
+common::app!(AccountForm, AccountFormMessage);
+
+/// The rules of garde keep the form from building, also those of nested structs
+fn garde_rules_checked() {
+    let account = Account {
+        name: "Someone".to_string(),
+        age: 30,
+        address: Address {
+            street: "Main Street".to_string(),
+            zip: 12345,
+        },
+    };
+    assert_eq!(
+        AccountForm::from_value(account.clone()).build(),
+        Some(account.clone())
+    );
+    let minor = Account {
+        age: 17,
+        ..account.clone()
+    };
+    assert!(AccountForm::from_value(minor).build().is_none());
+    let nowhere = Account {
+        address: Address {
+            zip: 1,
+            ..account.address.clone()
+        },
+        ..account
+    };
+    assert!(AccountForm::from_value(nowhere).build().is_none());
+    assert!(AccountForm::default().build().is_none());
+}
+
+fn main() {
+    garde_rules_checked();
+}
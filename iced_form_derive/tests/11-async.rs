@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    #[form(validate(range(min = 1024)))]
+    #[form(validate_async = port_free)]
+    port: u16,
+}
+
+async fn port_free(port: u16) -> Result<(), String> {
+    match std::net::TcpListener::bind(("127.0.0.1", port)) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+common::app!(ProxyForm, ProxyFormMessage);
+
+fn port_checked(form: &mut ProxyForm, generation: u64, result: Result<(), String>) {
+    let _ = form.update(ProxyFormMessage::Port(form_field::Message::Checked {
+        generation,
+        result,
+    }));
+}
+
+fn proxy() -> Proxy {
+    Proxy {
+        host: "localhost".to_string(),
+        port: 8080,
+    }
+}
+
+/// A value with an asynchronous validator does not build before it was checked
+fn initial_value_pending() {
+    let mut form = ProxyForm::from_value(proxy());
+    assert!(form.port.is_pending());
+    assert!(form.build().is_none());
+    let _ = form.init();
+    port_checked(&mut form, 1, Ok(()));
+    assert_eq!(form.build(), Some(proxy()));
+
+    let mut form = ProxyForm::from_value(proxy());
+    let _ = form.init();
+    port_checked(&mut form, 1, Err("Port in use".to_string()));
+    assert_eq!(form.port.invalid_reason(), Some("Port in use"));
+    assert!(form.build().is_none());
+}
+
+/// Values failing the other rules are not checked, results for outdated inputs are dropped
+fn outdated_checks_dropped() {
+    let form = ProxyForm::from_value(Proxy {
+        port: 80,
+        ..proxy()
+    });
+    assert!(!form.port.is_pending());
+
+    let mut form = ProxyForm::from_value(proxy());
+    let _ = form.init();
+    let _ = form.update(ProxyFormMessage::Port(form_field::Message::Value((
+        8081,
+        "8081".to_string(),
+    ))));
+    port_checked(&mut form, 1, Ok(()));
+    assert!(form.port.is_pending());
+    port_checked(&mut form, 2, Ok(()));
+    assert_eq!(
+        form.build(),
+        Some(Proxy {
+            port: 8081,
+            ..proxy()
+        })
+    );
+}
+
+fn main() {
+    initial_value_pending();
+    outdated_checks_dropped();
+}
@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Network {
+    Direct,
+    Proxied { proxy: Proxy },
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::Proxied {
+            proxy: Proxy::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Config {
+    name: String,
+    proxy: Proxy,
+    network: Network,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+fn error(path: &str, error: &str) -> (String, String) {
+    (path.to_string(), error.to_string())
+}
+
+fn edit_name(form: &mut ConfigForm, message: form_field::Message<String>) {
+    let _ = form.update(ConfigFormMessage::Name(message));
+}
+
+/// Errors are placed below the fields at their dotted paths, through nested and variant forms
+fn errors_applied_at_paths() {
+    let mut form = ConfigForm::from_value(Config::default());
+    form.apply_errors(
+        [
+            ("name", "Name taken"),
+            ("proxy.port", "Port in use"),
+            ("network.proxy.host", "Unknown host"),
+        ]
+        .into_iter()
+        .collect(),
+    );
+    assert_eq!(form.applied_errors, [error("name", "Name taken")]);
+    assert_eq!(form.proxy.applied_errors, [error("port", "Port in use")]);
+    let Some(NetworkEnumForm::Proxied(proxied)) = &form.network.form else {
+        panic!("the Proxied variant is not selected");
+    };
+    assert_eq!(
+        proxied.proxy.applied_errors,
+        [error("host", "Unknown host")]
+    );
+}
+
+/// Applied errors are kept until their field is edited, losing focus does not clear them
+fn applied_errors_kept_until_edited() {
+    let mut form = ConfigForm::from_value(Config::default());
+    form.apply_errors([("name", "Name taken")].into_iter().collect());
+    edit_name(&mut form, form_field::Message::Blur);
+    assert_eq!(form.applied_errors, [error("name", "Name taken")]);
+    edit_name(
+        &mut form,
+        form_field::Message::Value(("other".to_string(), "other".to_string())),
+    );
+    assert!(form.applied_errors.is_empty());
+}
+
+fn main() {
+    errors_applied_at_paths();
+    applied_errors_kept_until_edited();
+}
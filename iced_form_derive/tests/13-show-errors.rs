@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, validate::FieldErrors, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    #[form(validate(range(min = 1)))]
+    port: u16,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(show_errors = "on_change")]
+struct Retry {
+    #[form(validate(range(max = 10)))]
+    attempts: u8,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(show_errors = "on_submit", validate = Price::check)]
+struct Price {
+    cost: f64,
+    tax: f64,
+}
+
+impl Price {
+    fn check(&self) -> Result<(), FieldErrors> {
+        match self.tax < self.cost {
+            true => Ok(()),
+            false => Err(vec![("tax", "Must be below the cost".to_string())]),
+        }
+    }
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Network {
+    Direct,
+    Proxied { proxy: Proxy },
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::Proxied {
+            proxy: Proxy::default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Listener {
+    #[form(validate_async = port_free)]
+    port: u16,
+}
+
+async fn port_free(port: u16) -> Result<(), String> {
+    match std::net::TcpListener::bind(("127.0.0.1", port)) {
+        Ok(_) => Ok(()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+common::app!(ProxyForm, ProxyFormMessage);
+
+fn invalid_input<T: Clone>() -> form_field::Message<T> {
+    form_field::Message::Input {
+        input: "x".to_string(),
+        invalid_reason: "Expected a number".to_string(),
+    }
+}
+
+/// Errors show once the field lost focus, or right away or only after submit as the policy says
+fn errors_shown_per_policy() {
+    let mut form = ProxyForm::default();
+    let _ = form.update(ProxyFormMessage::Port(invalid_input()));
+    assert_eq!(form.port.error(), None);
+    let _ = form.update(ProxyFormMessage::Port(form_field::Message::Blur));
+    assert_eq!(form.port.error(), Some("Expected a number"));
+
+    let mut form = RetryForm::default();
+    let _ = form.update(RetryFormMessage::Attempts(invalid_input()));
+    assert_eq!(form.attempts.error(), Some("Expected a number"));
+
+    let mut form = PriceForm::default();
+    let _ = form.update(PriceFormMessage::Cost(invalid_input()));
+    let _ = form.update(PriceFormMessage::Cost(form_field::Message::Blur));
+    assert_eq!(form.cost.error(), None);
+    let _ = form.update(PriceFormMessage::SubmitAttempted);
+    assert_eq!(form.cost.error(), Some("Expected a number"));
+}
+
+/// Errors of struct validators are known as soon as a form starts, for Submit to reveal them
+fn struct_errors_from_start() {
+    let mut form = PriceForm::from_value(Price {
+        cost: 10.0,
+        tax: 20.0,
+    });
+    assert_eq!(form.first_invalid().as_deref(), Some("tax"));
+    assert_eq!(form.shown_error("tax"), None);
+    let _ = form.update(PriceFormMessage::SubmitAttempted);
+    assert_eq!(form.first_invalid().as_deref(), Some("tax"));
+    assert_eq!(form.shown_error("tax"), Some("Must be below the cost"));
+}
+
+/// Submit reveals the errors of the fields of the variant picked
+fn enum_submit_reveals_errors() {
+    let mut form = NetworkForm::from_value(Network::default());
+    assert_eq!(form.first_invalid().as_deref(), Some("proxy.port"));
+    let _ = form.update(NetworkFormMessage::SubmitAttempted);
+    let Some(NetworkEnumForm::Proxied(proxied)) = &form.form else {
+        panic!("the Proxied variant is not selected");
+    };
+    assert!(proxied.proxy.port.error().is_some());
+}
+
+fn port_checked(form: &mut ListenerForm, result: Result<(), String>) {
+    let _ = form.update(ListenerFormMessage::Port(form_field::Message::Checked {
+        generation: 0,
+        result,
+    }));
+}
+
+/// Submit pressed while a value is checked waits for the check, an edit drops it
+fn submit_waits_for_checks() {
+    let listener = Listener { port: 8080 };
+    let mut form = ListenerForm::from_value(listener.clone());
+    let _ = form.update(ListenerFormMessage::SubmitAttempted);
+    assert!(form.submit_queued && !form.submitted);
+    port_checked(&mut form, Ok(()));
+    assert!(!form.submit_queued && !form.submitted);
+    assert_eq!(form.build(), Some(listener.clone()));
+
+    let mut form = ListenerForm::from_value(listener.clone());
+    let _ = form.update(ListenerFormMessage::SubmitAttempted);
+    port_checked(&mut form, Err("Port in use".to_string()));
+    assert!(!form.submit_queued && form.submitted);
+    assert_eq!(form.first_invalid().as_deref(), Some("port"));
+
+    let mut form = ListenerForm::from_value(listener);
+    let _ = form.update(ListenerFormMessage::SubmitAttempted);
+    let _ = form.update(ListenerFormMessage::Port(form_field::Message::Value((
+        8081,
+        "8081".to_string(),
+    ))));
+    assert!(!form.submit_queued);
+}
+
+fn main() {
+    errors_shown_per_policy();
+    struct_errors_from_start();
+    enum_submit_reveals_errors();
+    submit_waits_for_checks();
+}
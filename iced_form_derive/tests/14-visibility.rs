@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, validate::FieldErrors, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, PartialEq)]
+#[form(validate = Discount::check)]
+struct Discount {
+    enabled: bool,
+    #[form(visible_if = "enabled")]
+    percent: u8,
+    #[form(visible_if = |builder| builder.percent.is_some_and(|percent| percent > 50))]
+    reason: String,
+}
+
+impl Default for Discount {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            percent: 0,
+            reason: "none".to_string(),
+        }
+    }
+}
+
+impl Discount {
+    fn check(&self) -> Result<(), FieldErrors> {
+        match self.percent {
+            1..=100 => Ok(()),
+            _ => Err(vec![("percent", "Must be within 1 and 100".to_string())]),
+        }
+    }
+}
+
+common::app!(DiscountForm, DiscountFormMessage);
+
+fn edit_percent(form: &mut DiscountForm, percent: u8) {
+    let _ = form.update(DiscountFormMessage::Percent(form_field::Message::Value((
+        percent,
+        percent.to_string(),
+    ))));
+}
+
+/// Errors of struct validators on hidden fields do not keep the form from building
+fn hidden_errors_dropped() {
+    assert_eq!(DiscountForm::default().build(), Some(Discount::default()));
+    let mut form = DiscountForm::from_value(Discount {
+        enabled: true,
+        ..Discount::default()
+    });
+    assert!(form.build().is_none());
+    let _ = form.update(DiscountFormMessage::Enabled(false));
+    assert!(form.build().is_some());
+}
+
+/// Hidden fields are built with their default value rather than the one they were left with
+fn hidden_fields_built_with_default() {
+    let mut form = DiscountForm::from_value(Discount {
+        enabled: true,
+        percent: 80,
+        reason: "clearance".to_string(),
+    });
+    edit_percent(&mut form, 20);
+    assert_eq!(
+        form.build(),
+        Some(Discount {
+            enabled: true,
+            percent: 20,
+            reason: "none".to_string(),
+        })
+    );
+    let _ = form.update(DiscountFormMessage::Enabled(false));
+    assert_eq!(form.build(), Some(Discount::default()));
+}
+
+fn main() {
+    hidden_errors_dropped();
+    hidden_fields_built_with_default();
+}
@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Settings {
+    enabled: bool,
+    #[form(readonly)]
+    version: String,
+    #[form(enabled_if = "enabled")]
+    proxy: Proxy,
+    #[form(enabled_if = |builder| builder.retries.is_some_and(|retries| retries > 0))]
+    backoff: f64,
+    retries: usize,
+}
+
+common::app!(SettingsForm, SettingsFormMessage);
+
+fn value<T: Clone + ToString>(value: T) -> form_field::Message<T> {
+    let input = value.to_string();
+    form_field::Message::Value((value, input))
+}
+
+fn edit_port(form: &mut SettingsForm, port: u16) {
+    let _ = form.update(SettingsFormMessage::Proxy(ProxyFormMessage::Port(value(
+        port,
+    ))));
+}
+
+fn settings() -> Settings {
+    Settings {
+        enabled: true,
+        version: "1.0".to_string(),
+        proxy: Proxy {
+            host: "localhost".to_string(),
+            port: 8080,
+        },
+        backoff: 0.5,
+        retries: 3,
+    }
+}
+
+/// Read-only fields ignore their input until made editable again, at any depth
+fn readonly_blocks_updates() {
+    let mut form = SettingsForm::from_value(settings());
+    form.set_readonly("proxy.port", true);
+    edit_port(&mut form, 8081);
+    assert_eq!(form.build(), Some(settings()));
+    form.set_readonly("proxy.port", false);
+    form.set_readonly("", true);
+    edit_port(&mut form, 8081);
+    let _ = form.update(SettingsFormMessage::Enabled(false));
+    assert_eq!(form.build(), Some(settings()));
+    form.set_readonly("", false);
+    edit_port(&mut form, 8081);
+    let mut edited = settings();
+    edited.proxy.port = 8081;
+    assert_eq!(form.build(), Some(edited));
+}
+
+/// `readonly` fields never take input, `enabled_if` ones only while their condition holds
+fn attributes_disable_fields() {
+    let mut form = SettingsForm::from_value(settings());
+    let _ = form.update(SettingsFormMessage::Version(value("2.0".to_string())));
+    assert_eq!(form.build(), Some(settings()));
+
+    let _ = form.update(SettingsFormMessage::Enabled(false));
+    edit_port(&mut form, 8081);
+    let _ = form.update(SettingsFormMessage::Retries(value(0)));
+    let _ = form.update(SettingsFormMessage::Backoff(value(1.5)));
+    assert_eq!(
+        form.build(),
+        Some(Settings {
+            enabled: false,
+            retries: 0,
+            ..settings()
+        })
+    );
+
+    let _ = form.update(SettingsFormMessage::Enabled(true));
+    edit_port(&mut form, 8081);
+    assert_eq!(form.build().map(|settings| settings.proxy.port), Some(8081));
+}
+
+fn main() {
+    readonly_blocks_updates();
+    attributes_disable_fields();
+}
@@ -0,0 +1,62 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(computed(label = "Total", with = Price::total))]
+struct Price {
+    cost: f64,
+    tax: f64,
+}
+
+impl Price {
+    fn total(builder: &PriceBuilder) -> String {
+        match (builder.cost, builder.tax) {
+            (Some(cost), Some(tax)) => format!("{:.2}", cost + tax),
+            _ => "-".to_string(),
+        }
+    }
+}
+
+common::app!(PriceForm, PriceFormMessage);
+
+fn edit_tax(form: &mut PriceForm, message: form_field::Message<f64>) {
+    let _ = form.update(PriceFormMessage::Tax(message));
+}
+
+/// Computed rows follow the values shown, also when an input is not a valid value
+fn computed_from_shown_values() {
+    let mut form = PriceForm::from_value(Price {
+        cost: 10.0,
+        tax: 2.0,
+    });
+    assert_eq!(Price::total(&form.shown_builder()), "12.00");
+    edit_tax(
+        &mut form,
+        form_field::Message::Value((3.5, "3.5".to_string())),
+    );
+    assert_eq!(Price::total(&form.shown_builder()), "13.50");
+    edit_tax(
+        &mut form,
+        form_field::Message::Input {
+            input: "x".to_string(),
+            invalid_reason: "Expected a number".to_string(),
+        },
+    );
+    assert_eq!(Price::total(&form.shown_builder()), "-");
+}
+
+/// Computed rows are found by their label
+fn computed_rows_searched() {
+    let form = PriceForm::default();
+    assert!(Form::view_filtered::<iced::Theme>(&form, "total").is_some());
+    assert!(Form::view_filtered::<iced::Theme>(&form, "discount").is_none());
+}
+
+fn main() {
+    computed_from_shown_values();
+    computed_rows_searched();
+}
@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, PartialEq, Builder, FormBuilder, Default)]
+struct Record {
+    name: String,
+    #[form(skip)]
+    id: u64,
+    #[form(skip, default = 1)]
+    revision: u32,
+}
+
+common::app!(RecordForm, RecordFormMessage);
+
+/// Skipped fields start from their `default` in fresh forms and keep the values loaded
+fn skipped_fields_kept() {
+    assert_eq!(
+        RecordForm::default().build(),
+        Some(Record {
+            revision: 1,
+            ..Record::default()
+        })
+    );
+    let record = Record {
+        name: "draft".to_string(),
+        id: 42,
+        revision: 7,
+    };
+    let mut form = RecordForm::from_value(record.clone());
+    assert_eq!(form.build(), Some(record.clone()));
+    let _ = form.update(RecordFormMessage::Name(form_field::Message::Value((
+        "final".to_string(),
+        "final".to_string(),
+    ))));
+    assert_eq!(
+        form.build(),
+        Some(Record {
+            name: "final".to_string(),
+            ..record
+        })
+    );
+}
+
+fn main() {
+    skipped_fields_kept();
+}
@@ -0,0 +1,56 @@
+#![allow(dead_code)]
+mod common;
+
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Mode {
+    Basic,
+    #[form(rename = "Expert")]
+    Advanced,
+    StepByStep,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+#[form(use_display, no_all)]
+enum VehicleType {
+    TwoWheeler,
+    FourWheeler,
+}
+
+impl VehicleType {
+    const ALL: [Self; 2] = [Self::FourWheeler, Self::TwoWheeler];
+}
+
+impl std::fmt::Display for VehicleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TwoWheeler => write!(f, "2-wheeler"),
+            Self::FourWheeler => write!(f, "4-wheeler"),
+        }
+    }
+}
+
+common::app!(ModeForm, ModeFormMessage);
+
+/// Variants are shown in title case unless renamed, `ALL` lists them in declaration order
+fn variants_labeled() {
+    assert_eq!(
+        Mode::ALL.map(|mode| mode.to_string()),
+        ["Basic", "Expert", "Step By Step"]
+    );
+}
+
+/// `use_display` and `no_all` leave `Display` and `ALL` to the enum
+fn hand_written_impls_used() {
+    assert_eq!(VehicleType::ALL[0].to_string(), "4-wheeler");
+    let mut form = VehicleTypeForm::default();
+    assert_eq!(form.build(), None);
+    let _ = form.update(VehicleTypeFormMessage::VehicleType(VehicleType::TwoWheeler));
+    assert_eq!(form.build(), Some(VehicleType::TwoWheeler));
+}
+
+fn main() {
+    variants_labeled();
+    hand_written_impls_used();
+}
@@ -0,0 +1,81 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::choice::{Orientation, Picker};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+#[form(widget = "segmented")]
+enum Mode {
+    #[default]
+    Basic,
+    Advanced,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+#[form(widget = "combo_box")]
+enum LogTarget {
+    #[default]
+    Stdout,
+    Stderr,
+    Syslog,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+#[form(widget = "radio", orientation = "vertical")]
+enum Network {
+    #[default]
+    Direct,
+    Proxied {
+        host: String,
+    },
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Config {
+    mode: Mode,
+    #[form(widget = "radio")]
+    fallback_mode: Mode,
+    log_target: LogTarget,
+    #[form(widget = "segmented", orientation = "vertical")]
+    network: Network,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+/// The widget of a field overrides the one of its enum
+fn field_widget_overrides_enum() {
+    let form = ConfigForm::default();
+    assert_eq!(
+        form.mode.choice.picker(),
+        Picker::Segmented(Orientation::Horizontal)
+    );
+    assert_eq!(
+        form.fallback_mode.choice.picker(),
+        Picker::Radio(Orientation::Horizontal)
+    );
+    assert_eq!(
+        NetworkForm::default().pick_list.choice.picker(),
+        Picker::Radio(Orientation::Vertical)
+    );
+    assert_eq!(
+        form.network.pick_list.choice.picker(),
+        Picker::Segmented(Orientation::Vertical)
+    );
+}
+
+/// Picking from the combo box selects the variant
+fn combo_box_picks() {
+    let mut form = ConfigForm::default();
+    assert_eq!(form.log_target.choice.picker(), Picker::ComboBox);
+    let _ = form.update(ConfigFormMessage::LogTarget(
+        LogTargetFormMessage::LogTarget(LogTarget::Syslog),
+    ));
+    assert_eq!(form.log_target.build(), Some(LogTarget::Syslog));
+}
+
+fn main() {
+    field_widget_overrides_enum();
+    combo_box_picks();
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+enum Mode {
+    Basic,
+    #[default]
+    Advanced,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Mirror {
+    Primary,
+    Backup,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Network {
+    Direct,
+    Proxied { proxy: Proxy, retries: usize },
+}
+
+impl Default for Network {
+    fn default() -> Self {
+        Self::Proxied {
+            proxy: Proxy {
+                host: "localhost".to_string(),
+                port: 8080,
+            },
+            retries: 3,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Config {
+    mode: Mode,
+    network: Network,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+/// The `Default` of an enum is preselected, enums without one start with nothing picked
+fn default_variant_preselected() {
+    assert_eq!(ModeForm::default().build(), Some(Mode::Advanced));
+    assert_eq!(MirrorForm::default().build(), None);
+}
+
+/// The form of an enum starts from the field values of its `Default`, also when nested
+fn enum_starts_from_default() {
+    assert_eq!(NetworkForm::default().build(), Some(Network::default()));
+    assert_eq!(ConfigForm::default().build(), Some(Config::default()));
+}
+
+fn main() {
+    default_variant_preselected();
+    enum_starts_from_default();
+}
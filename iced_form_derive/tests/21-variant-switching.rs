@@ -0,0 +1,93 @@
+#![allow(dead_code)]
+mod common;
+
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Schedule {
+    Daily { hour: u8 },
+    Weekly { day: String, hour: u8 },
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+#[form(carry_over)]
+enum Contact {
+    Phone {
+        #[form(validate(length(min = 6, max = 15)))]
+        handle: String,
+    },
+    Email {
+        #[form(validate(regex = "^[^@]+@[^@]+$"))]
+        handle: String,
+    },
+}
+
+common::app!(ContactForm, ContactFormMessage);
+
+fn pick_schedule(form: &mut ScheduleForm, kind: ScheduleKind) {
+    let _ = form.update(ScheduleFormMessage::PickList(
+        ScheduleKindFormMessage::ScheduleKind(kind),
+    ));
+}
+
+fn pick_contact(form: &mut ContactForm, kind: ContactKind) {
+    let _ = form.update(ContactFormMessage::PickList(
+        ContactKindFormMessage::ContactKind(kind),
+    ));
+}
+
+fn edit_email(form: &mut ContactForm, handle: &str) {
+    let _ = form.update(ContactFormMessage::Form(ContactEnumFormMessage::Email(
+        EmailVarFormMessage::Handle(form_field::Message::Value((
+            handle.to_string(),
+            handle.to_string(),
+        ))),
+    )));
+}
+
+/// Variants switched away from keep their input, fields are only shared with `carry_over`
+fn variant_forms_kept() {
+    let mut form = ScheduleForm::from_value(Schedule::Daily { hour: 6 });
+    pick_schedule(&mut form, ScheduleKind::Weekly);
+    assert_eq!(
+        form.build(),
+        Some(Schedule::Weekly {
+            day: String::new(),
+            hour: 0
+        })
+    );
+    pick_schedule(&mut form, ScheduleKind::Daily);
+    assert_eq!(form.build(), Some(Schedule::Daily { hour: 6 }));
+}
+
+/// The input carried over to another variant is checked against the validators of that variant
+fn carry_over_keeps_validators() {
+    let mut form = ContactForm::from_value(Contact::Phone {
+        handle: "5550123".to_string(),
+    });
+    pick_contact(&mut form, ContactKind::Email);
+    assert_eq!(form.build(), None);
+    edit_email(&mut form, "me@example.com");
+    assert_eq!(
+        form.build(),
+        Some(Contact::Email {
+            handle: "me@example.com".to_string()
+        })
+    );
+    edit_email(&mut form, "me@x");
+    pick_contact(&mut form, ContactKind::Phone);
+    assert_eq!(form.build(), None);
+    pick_contact(&mut form, ContactKind::Email);
+    assert_eq!(
+        form.build(),
+        Some(Contact::Email {
+            handle: "me@x".to_string()
+        })
+    );
+}
+
+fn main() {
+    variant_forms_kept();
+    carry_over_keeps_validators();
+}
@@ -0,0 +1,66 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::Form;
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Price {
+    cost: f64,
+    tax: f64,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Listing {
+    Auction(Price),
+    Lot(u32),
+    Reference(#[form(label = "Code")] String),
+    Lease(#[form(label = "Months")] u32, f64),
+}
+
+common::app!(ListingForm, ListingFormMessage);
+
+/// Tuple fields are shown with their label, newtype variants in place of the variant
+fn tuple_fields_labeled() {
+    let form = ListingForm::from_value(Listing::Lease(12, 950.0));
+    let Some(ListingEnumForm::Lease(lease)) = &form.form else {
+        panic!("the Lease variant is not selected");
+    };
+    assert_eq!(lease.field_0.label(), "Months");
+    assert_eq!(lease.field_1.label(), "Field 1");
+
+    let form = ListingForm::from_value(Listing::Lot(7));
+    let Some(ListingEnumForm::Lot(lot)) = &form.form else {
+        panic!("the Lot variant is not selected");
+    };
+    assert_eq!(lot.field_0.label(), "");
+
+    let form = ListingForm::from_value(Listing::Reference("A-1".to_string()));
+    let Some(ListingEnumForm::Reference(reference)) = &form.form else {
+        panic!("the Reference variant is not selected");
+    };
+    assert_eq!(reference.field_0.label(), "Code");
+}
+
+/// Values of tuple and newtype variants are loaded and built back
+fn tuple_variants_round_trip() {
+    for listing in [
+        Listing::Auction(Price {
+            cost: 100.0,
+            tax: 10.0,
+        }),
+        Listing::Lot(7),
+        Listing::Lease(12, 950.0),
+    ] {
+        assert_eq!(
+            ListingForm::from_value(listing.clone()).build(),
+            Some(listing)
+        );
+    }
+}
+
+fn main() {
+    tuple_fields_labeled();
+    tuple_variants_round_trip();
+}
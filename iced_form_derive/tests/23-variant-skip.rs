@@ -0,0 +1,91 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{form_field, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+enum LogTarget {
+    #[default]
+    Stdout,
+    Stderr,
+    #[deprecated]
+    Journald,
+    #[form(skip)]
+    File,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+#[form(no_all)]
+enum Fuel {
+    Petrol,
+    Electric,
+    #[form(skip)]
+    Coal,
+}
+
+impl Fuel {
+    const ALL: [Self; 3] = [Self::Petrol, Self::Electric, Self::Coal];
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Listing {
+    Rent {
+        deposit: f64,
+        furnished: bool,
+    },
+    #[form(skip)]
+    Lease(u32, f64),
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, PartialEq)]
+struct Rental {
+    listing: Listing,
+}
+
+impl Default for Rental {
+    fn default() -> Self {
+        Self {
+            listing: Listing::Lease(12, 950.0),
+        }
+    }
+}
+
+common::app!(RentalForm, RentalFormMessage);
+
+/// Skipped variants are left out of the picker, also from a hand-written `ALL`
+fn skipped_variants_not_offered() {
+    #[allow(deprecated)]
+    let offered = [LogTarget::Stdout, LogTarget::Stderr, LogTarget::Journald];
+    assert_eq!(LogTargetForm::default().choice.options(), offered);
+    assert_eq!(
+        FuelForm::default().choice.options(),
+        [Fuel::Petrol, Fuel::Electric]
+    );
+}
+
+/// A value of a skipped variant is shown read-only, also when nested in a struct
+fn skipped_variant_stays_readonly() {
+    let mut form = RentalForm::default();
+    let _ = form.update(RentalFormMessage::Listing(ListingFormMessage::Form(
+        ListingEnumFormMessage::Lease(LeaseVarFormMessage::Field0(form_field::Message::Value((
+            24,
+            "24".to_string(),
+        )))),
+    )));
+    assert_eq!(form.build(), Some(Rental::default()));
+
+    let rental = Rental {
+        listing: Listing::Rent {
+            deposit: 1800.0,
+            furnished: true,
+        },
+    };
+    assert_eq!(RentalForm::from_value(rental.clone()).build(), Some(rental));
+}
+
+fn main() {
+    skipped_variants_not_offered();
+    skipped_variant_stays_readonly();
+}
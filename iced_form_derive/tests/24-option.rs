@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+mod common;
+
+use derive_builder::Builder;
+use iced_form::{choice::OrNone, option, Form};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+enum Mode {
+    #[default]
+    Basic,
+    Advanced,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Default)]
+enum Network {
+    #[default]
+    Direct,
+    Proxied {
+        host: String,
+        port: u16,
+    },
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+#[form(navigation)]
+struct Config {
+    name: String,
+    #[form(none = "Inherit", widget = "radio")]
+    fallback_mode: Option<Mode>,
+    fallback_network: Option<Network>,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+/// `Option<Enum>` fields start from the value they are given, `None` is picked as any variant
+fn option_round_trips() {
+    let config = Config {
+        name: "main".to_string(),
+        fallback_mode: Some(Mode::Advanced),
+        fallback_network: Some(Network::Proxied {
+            host: "localhost".to_string(),
+            port: 8080,
+        }),
+    };
+    let mut form = ConfigForm::from_value(config.clone());
+    assert_eq!(form.build(), Some(config.clone()));
+    assert_eq!(ConfigForm::default().build(), Some(Config::default()));
+
+    let _ = form.update(ConfigFormMessage::FallbackMode(option::Message::Select(
+        OrNone::None("Inherit".to_string()),
+    )));
+    let _ = form.update(ConfigFormMessage::FallbackNetwork(option::Message::Select(
+        OrNone::Some(NetworkKind::Direct),
+    )));
+    assert_eq!(
+        form.build(),
+        Some(Config {
+            fallback_mode: None,
+            fallback_network: Some(Network::Direct),
+            ..config
+        })
+    );
+}
+
+fn nav_paths(form: &ConfigForm) -> Vec<String> {
+    let tree = form.nav_tree("Config", "").unwrap();
+    tree.children.into_iter().map(|node| node.path).collect()
+}
+
+/// `Option<Enum>` fields keep their entry in the navigation tree whatever is picked
+fn option_nav_entry_stable() {
+    let mut form = ConfigForm::default();
+    let paths = nav_paths(&form);
+    assert!(paths.contains(&"fallback_network".to_string()));
+    assert!(!paths.contains(&"fallback_mode".to_string()));
+    let _ = form.update(ConfigFormMessage::FallbackNetwork(option::Message::Select(
+        OrNone::Some(NetworkKind::Proxied),
+    )));
+    assert_eq!(nav_paths(&form), paths);
+}
+
+fn main() {
+    option_round_trips();
+    option_nav_entry_stable();
+}
@@ -0,0 +1,88 @@
+#![allow(dead_code)]
+mod common;
+
+use std::collections::BTreeSet;
+
+use derive_builder::Builder;
+use iced_form::{
+    set::{self, SetForm},
+    Form,
+};
+use iced_form_derive::FormBuilder;
+
+#[derive(Clone, Debug, FormBuilder, PartialEq, Eq, PartialOrd, Ord)]
+enum Permission {
+    Read,
+    Write,
+    Admin,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum Mode {
+    Basic,
+    Advanced,
+}
+
+#[derive(Clone, Debug, FormBuilder, PartialEq)]
+enum LogTarget {
+    Stdout,
+    Stderr,
+    #[form(skip)]
+    File,
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder, Default, PartialEq)]
+struct Config {
+    #[form(select(min = 1, max = 2, shortcuts))]
+    permissions: BTreeSet<Permission>,
+    modes: Vec<Mode>,
+}
+
+common::app!(ConfigForm, ConfigFormMessage);
+
+/// Collections of unit enums start from the value they are given, a `Vec` comes back in the
+/// order of `ALL` without duplicates
+fn set_round_trips() {
+    let form = ConfigForm::from_value(Config {
+        permissions: BTreeSet::from([Permission::Write, Permission::Read]),
+        modes: vec![Mode::Advanced, Mode::Basic, Mode::Advanced],
+    });
+    assert_eq!(
+        form.build(),
+        Some(Config {
+            permissions: BTreeSet::from([Permission::Read, Permission::Write]),
+            modes: vec![Mode::Basic, Mode::Advanced],
+        })
+    );
+}
+
+/// Selections outside of the bounds do not build
+fn bounds_reject() {
+    assert!(ConfigForm::default().build().is_none());
+    let mut form = ConfigForm::from_value(Config {
+        permissions: BTreeSet::from([Permission::Read, Permission::Write, Permission::Admin]),
+        modes: Vec::new(),
+    });
+    assert!(form.build().is_none());
+    let _ = form.update(ConfigFormMessage::Permissions(set::Message::Toggled(
+        Permission::Admin,
+        false,
+    )));
+    assert!(form.build().is_some());
+}
+
+/// Values left out of `ALL` stay checked, read-only, and are built along with the others
+fn set_keeps_unlisted() {
+    let mut form =
+        SetForm::<LogTarget, Vec<_>>::from_value(vec![LogTarget::File, LogTarget::Stderr]);
+    assert_eq!(form.build(), Some(vec![LogTarget::Stderr, LogTarget::File]));
+    let _ = form.update(set::Message::Toggled(LogTarget::File, false));
+    let _ = form.update(set::Message::All(false));
+    assert_eq!(form.build(), Some(vec![LogTarget::File]));
+}
+
+fn main() {
+    set_round_trips();
+    bounds_reject();
+    set_keeps_unlisted();
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "bitflags")]
+#![allow(dead_code)]
+mod common;
+
+use bitflags::bitflags;
+use derive_builder::Builder;
+use iced_form_derive::FormBuilder;
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Permissions: u8 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    struct Interrupts: u32 {
+        const TIMER = 1 << 0;
+        const UART = 1 << 4;
+        const DMA = 1 << 8;
+    }
+}
+
+#[derive(Clone, Debug, Builder, FormBuilder)]
+struct Device {
+    name: String,
+    #[form(flags)]
+    permissions: Permissions,
+    #[form(flags(hex), label = "IRQ mask")]
+    interrupts: Interrupts,
+}
+
+impl Default for Device {
+    fn default() -> Self {
+        Self {
+            name: "uart0".to_string(),
+            permissions: Permissions::READ | Permissions::WRITE,
+            interrupts: Interrupts::UART | Interrupts::from_bits_retain(1 << 12),
+        }
+    }
+}
+
+common::app!(DeviceForm, DeviceFormMessage);
+
+/// The flags start from the `Default` of the struct, unnamed bits included
+fn flags_start_from_default() {
+    let device = DeviceForm::default().build().unwrap();
+    assert_eq!(device.permissions, Permissions::READ | Permissions::WRITE);
+    assert_eq!(device.interrupts.bits(), (1 << 4) | (1 << 12));
+}
+
+fn main() {
+    flags_start_from_default();
+}
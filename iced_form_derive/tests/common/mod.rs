@@ -0,0 +1,37 @@
+/// Declares the `App` showing a form of type `$form`, checking that the generated form fits into
+/// an `Application` as its documentation suggests.
+macro_rules! app {
+    ($form:ident, $message:ident) => {
+        struct App {
+            form: $form,
+        }
+
+        impl ::iced::advanced::Application for App {
+            type Executor = ::iced::executor::Default;
+            type Message = $message;
+            type Renderer = ::iced::Renderer;
+            type Theme = ::iced::Theme;
+            type Flags = ();
+
+            fn new(_: Self::Flags) -> (Self, ::iced::Command<Self::Message>) {
+                let mut form = $form::default();
+                let command = ::iced_form::Form::init(&mut form);
+                (Self { form }, command)
+            }
+
+            fn title(&self) -> String {
+                stringify!($form).to_string()
+            }
+
+            fn update(&mut self, message: Self::Message) -> ::iced::Command<Self::Message> {
+                self.form.update(message)
+            }
+
+            fn view(&self) -> ::iced::Element<'_, Self::Message, Self::Theme, Self::Renderer> {
+                self.form.view()
+            }
+        }
+    };
+}
+
+pub(crate) use app;
@@ -4,4 +4,30 @@ fn tests() {
     t.pass("tests/01-form-derive.rs");
     t.pass("tests/02-nested-and-enum.rs");
     t.pass("tests/03-handling-mods.rs");
+    t.pass("tests/04-layout.rs");
+    t.pass("tests/05-search.rs");
+    t.pass("tests/06-navigation.rs");
+    t.pass("tests/07-field-validators.rs");
+    t.pass("tests/08-cross-field.rs");
+    #[cfg(feature = "validator")]
+    t.pass("tests/09-validator.rs");
+    #[cfg(feature = "garde")]
+    t.pass("tests/10-garde.rs");
+    t.pass("tests/11-async.rs");
+    t.pass("tests/12-apply-errors.rs");
+    t.pass("tests/13-show-errors.rs");
+    t.pass("tests/14-visibility.rs");
+    t.pass("tests/15-readonly.rs");
+    t.pass("tests/16-computed.rs");
+    t.pass("tests/17-skip.rs");
+    t.pass("tests/18-unit-enum.rs");
+    t.pass("tests/19-pickers.rs");
+    t.pass("tests/20-enum-default.rs");
+    t.pass("tests/21-variant-switching.rs");
+    t.pass("tests/22-tuple-variants.rs");
+    t.pass("tests/23-variant-skip.rs");
+    t.pass("tests/24-option.rs");
+    t.pass("tests/25-set.rs");
+    #[cfg(feature = "bitflags")]
+    t.pass("tests/26-bitflags.rs");
 }